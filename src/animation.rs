@@ -0,0 +1,362 @@
+// Keyframe evaluation for animated reticles.
+// Tracks address config fields by their serialized path, so any numeric value a
+// profile stores can be animated without a per-field table here.
+
+use serde_json::Value;
+
+use crate::{AnimationTrack, CrosshairConfig, Easing};
+
+// Field paths under this root are never animated or listed.
+const ANIMATION_ROOT: &str = "animation";
+
+#[derive(Clone, Debug, PartialEq)]
+enum PathStep {
+    Key(String),
+    Index(usize),
+    // `[*]`: every element of the array.
+    All,
+}
+
+fn parse_path(path: &str) -> Option<Vec<PathStep>> {
+    let mut steps = Vec::new();
+    for part in path.split('.') {
+        let (key, mut rest) = match part.find('[') {
+            Some(open) => part.split_at(open),
+            None => (part, ""),
+        };
+        if !key.is_empty() {
+            steps.push(PathStep::Key(key.to_string()));
+        }
+        while let Some(inner) = rest.strip_prefix('[') {
+            let close = inner.find(']')?;
+            let index = &inner[..close];
+            steps.push(if index == "*" {
+                PathStep::All
+            } else {
+                PathStep::Index(index.parse().ok()?)
+            });
+            rest = &inner[close + 1..];
+        }
+        if !rest.is_empty() {
+            return None;
+        }
+    }
+    match steps.first() {
+        Some(PathStep::Key(root)) if root != ANIMATION_ROOT => Some(steps),
+        _ => None,
+    }
+}
+
+// Writes `value` (or adds it, when `relative`) to every number the path reaches.
+// Integer fields stay integers so they still deserialize.
+fn apply_at(target: &mut Value, steps: &[PathStep], value: f64, relative: bool) -> bool {
+    let Some((step, rest)) = steps.split_first() else {
+        let Some(current) = target.as_f64() else {
+            return false;
+        };
+        let next = if relative { current + value } else { value };
+        *target = if target.is_u64() {
+            Value::from(next.round().max(0.0) as u64)
+        } else if target.is_i64() {
+            Value::from(next.round() as i64)
+        } else {
+            serde_json::Number::from_f64(next).map_or(Value::Null, Value::Number)
+        };
+        return !target.is_null();
+    };
+
+    match (step, target) {
+        (PathStep::Key(key), Value::Object(map)) => map
+            .get_mut(key)
+            .is_some_and(|child| apply_at(child, rest, value, relative)),
+        (PathStep::Index(idx), Value::Array(items)) => items
+            .get_mut(*idx)
+            .is_some_and(|child| apply_at(child, rest, value, relative)),
+        (PathStep::All, Value::Array(items)) => items.iter_mut().fold(false, |hit, child| {
+            apply_at(child, rest, value, relative) | hit
+        }),
+        _ => false,
+    }
+}
+
+fn ease(easing: Easing, t: f64) -> f64 {
+    match easing {
+        Easing::Linear => t,
+        Easing::EaseInOut => t * t * (3.0 - 2.0 * t),
+        Easing::Hold => 0.0,
+    }
+}
+
+impl AnimationTrack {
+    // Value at `time` seconds; before the first and after the last keyframe the
+    // nearest keyframe holds.
+    pub(crate) fn sample(&self, time: f64) -> Option<f64> {
+        let mut keys: Vec<_> = self.keyframes.iter().collect();
+        keys.sort_by(|a, b| a.time.total_cmp(&b.time));
+        let first = keys.first()?;
+        if time <= first.time {
+            return Some(first.value);
+        }
+        for pair in keys.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if time < b.time {
+                let span = b.time - a.time;
+                let t = if span > 0.0 {
+                    (time - a.time) / span
+                } else {
+                    1.0
+                };
+                return Some(a.value + (b.value - a.value) * ease(a.easing, t));
+            }
+        }
+        keys.last().map(|key| key.value)
+    }
+
+    pub(crate) fn is_valid_field(&self) -> bool {
+        parse_path(&self.field).is_some()
+    }
+}
+
+// The config with every track applied at `time` seconds. Tracks without a field
+// are skipped; a track that names a missing or non-numeric field, or drives a
+// value its field cannot hold, is an error (`track_error` reports it per track).
+pub(crate) fn config_at(config: &CrosshairConfig, time: f64) -> Result<CrosshairConfig, String> {
+    if config.animation.tracks.is_empty() {
+        return Ok(config.clone());
+    }
+    let mut value = serde_json::to_value(config).map_err(|e| format!("Animation failed: {}", e))?;
+
+    for track in &config.animation.tracks {
+        let Some(sample) = track.sample(time).filter(|_| !track.field.is_empty()) else {
+            continue;
+        };
+        let steps = parse_path(&track.field)
+            .ok_or_else(|| format!("'{}' is not an animatable field path", track.field))?;
+        if !apply_at(&mut value, &steps, sample, track.relative) {
+            return Err(format!("'{}' is not a numeric field", track.field));
+        }
+    }
+
+    serde_json::from_value(value).map_err(|e| format!("Animated value does not fit: {}", e))
+}
+
+// Why `track` cannot be applied, checked at every keyframe; in-between samples
+// stay within the keyframe values. None for tracks that are fine or have no field.
+pub(crate) fn track_error(config: &CrosshairConfig, track: &AnimationTrack) -> Option<String> {
+    if track.field.is_empty() {
+        return None;
+    }
+    let Some(steps) = parse_path(&track.field) else {
+        return Some(format!("'{}' is not an animatable field path", track.field));
+    };
+    let base = serde_json::to_value(config).ok()?;
+    for key in &track.keyframes {
+        let mut value = base.clone();
+        if !apply_at(&mut value, &steps, key.value, track.relative) {
+            return Some(format!("'{}' is not a numeric field", track.field));
+        }
+        if let Err(err) = serde_json::from_value::<CrosshairConfig>(value) {
+            return Some(format!(
+                "keyframe at {}s does not fit '{}': {}",
+                key.time, track.field, err
+            ));
+        }
+    }
+    None
+}
+
+// Sample times for one cycle, `1 / fps` apart; always at least one frame.
+pub(crate) fn frame_times(config: &CrosshairConfig) -> Vec<f64> {
+    let animation = &config.animation;
+    let fps = animation.fps.max(1) as f64;
+    let count = (animation.duration.max(0.0) * fps).round().max(1.0) as usize;
+    (0..count).map(|i| i as f64 / fps).collect()
+}
+
+// Every numeric field path in the config, for the track field picker. Arrays of
+// objects also get a `[*]` entry that targets all elements at once.
+pub(crate) fn numeric_fields(config: &CrosshairConfig) -> Vec<String> {
+    fn walk(value: &Value, path: String, out: &mut Vec<String>) {
+        match value {
+            Value::Number(_) => out.push(path),
+            Value::Object(map) => {
+                for (key, child) in map {
+                    if path.is_empty() && key == ANIMATION_ROOT {
+                        continue;
+                    }
+                    let child_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    walk(child, child_path, out);
+                }
+            }
+            Value::Array(items) => {
+                if items.len() > 1 && items.iter().all(Value::is_object) {
+                    walk(&items[0], format!("{}[*]", path), out);
+                }
+                for (idx, child) in items.iter().enumerate() {
+                    walk(child, format!("{}[{}]", path, idx), out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut out = Vec::new();
+    if let Ok(value) = serde_json::to_value(config) {
+        walk(&value, String::new(), &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Keyframe;
+
+    fn key(time: f64, value: f64, easing: Easing) -> Keyframe {
+        Keyframe {
+            time,
+            value,
+            easing,
+        }
+    }
+
+    fn track(field: &str, relative: bool, keyframes: Vec<Keyframe>) -> AnimationTrack {
+        AnimationTrack {
+            field: field.to_string(),
+            relative,
+            keyframes,
+        }
+    }
+
+    fn animated(tracks: Vec<AnimationTrack>) -> CrosshairConfig {
+        let mut config = CrosshairConfig::default();
+        config.animation.enabled = true;
+        config.animation.tracks = tracks;
+        config
+    }
+
+    #[test]
+    fn sample_holds_outside_the_keyframes_and_interpolates_between() {
+        // Out of order on purpose: keyframes are sorted by time before sampling.
+        let track = track(
+            "size",
+            false,
+            vec![
+                key(1.0, 20.0, Easing::Linear),
+                key(0.5, 10.0, Easing::Linear),
+            ],
+        );
+        assert_eq!(track.sample(0.0), Some(10.0));
+        assert_eq!(track.sample(0.5), Some(10.0));
+        assert_eq!(track.sample(0.75), Some(15.0));
+        assert_eq!(track.sample(1.0), Some(20.0));
+        assert_eq!(track.sample(3.0), Some(20.0));
+        assert_eq!(AnimationTrack::default().sample(0.0), None);
+    }
+
+    #[test]
+    fn hold_keeps_the_value_until_the_next_keyframe() {
+        let track = track(
+            "size",
+            false,
+            vec![key(0.0, 10.0, Easing::Hold), key(1.0, 20.0, Easing::Linear)],
+        );
+        assert_eq!(track.sample(0.0), Some(10.0));
+        assert_eq!(track.sample(0.99), Some(10.0));
+        assert_eq!(track.sample(1.0), Some(20.0));
+    }
+
+    #[test]
+    fn paths_parse_keys_indices_and_wildcards() {
+        assert_eq!(
+            parse_path("rings[1].color[3]"),
+            Some(vec![
+                PathStep::Key("rings".to_string()),
+                PathStep::Index(1),
+                PathStep::Key("color".to_string()),
+                PathStep::Index(3),
+            ])
+        );
+        assert_eq!(
+            parse_path("spokes[*].angle"),
+            Some(vec![
+                PathStep::Key("spokes".to_string()),
+                PathStep::All,
+                PathStep::Key("angle".to_string()),
+            ])
+        );
+        assert_eq!(parse_path("spokes[x].angle"), None);
+        assert_eq!(parse_path("spokes[0"), None);
+        assert_eq!(parse_path("animation.fps"), None);
+        assert_eq!(parse_path("[0]"), None);
+    }
+
+    #[test]
+    fn wildcard_tracks_drive_every_element() {
+        let config = animated(vec![track(
+            "spokes[*].angle",
+            true,
+            vec![key(0.0, 10.0, Easing::Linear)],
+        )]);
+        let Ok(frame) = config_at(&config, 0.0) else {
+            panic!("wildcard track should apply");
+        };
+        let angles: Vec<f64> = frame.spokes.iter().map(|spoke| spoke.angle).collect();
+        assert_eq!(angles, vec![55.0, 145.0, 235.0, 325.0]);
+    }
+
+    #[test]
+    fn integer_fields_stay_integers() {
+        let config = animated(vec![
+            track("size", false, vec![key(0.0, 100.4, Easing::Linear)]),
+            track(
+                "rings[0].color[0]",
+                false,
+                vec![key(0.0, 12.6, Easing::Linear)],
+            ),
+        ]);
+        let Ok(frame) = config_at(&config, 0.0) else {
+            panic!("integer tracks should apply");
+        };
+        assert_eq!(frame.size, 100);
+        assert_eq!(frame.rings[0].color.0, 13);
+    }
+
+    #[test]
+    fn non_numeric_paths_are_errors() {
+        let object = animated(vec![track(
+            "center_marker",
+            false,
+            vec![key(0.0, 1.0, Easing::Linear)],
+        )]);
+        assert_eq!(
+            config_at(&object, 0.0).err().as_deref(),
+            Some("'center_marker' is not a numeric field")
+        );
+        assert_eq!(
+            track_error(&object, &object.animation.tracks[0]).as_deref(),
+            Some("'center_marker' is not a numeric field")
+        );
+
+        let missing = animated(vec![track(
+            "rings[5].thickness",
+            false,
+            vec![key(0.0, 1.0, Easing::Linear)],
+        )]);
+        assert!(config_at(&missing, 0.0).is_err());
+
+        let bad_path = animated(vec![track(
+            "animation.fps",
+            false,
+            vec![key(0.0, 1.0, Easing::Linear)],
+        )]);
+        assert_eq!(
+            config_at(&bad_path, 0.0).err().as_deref(),
+            Some("'animation.fps' is not an animatable field path")
+        );
+    }
+}
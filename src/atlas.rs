@@ -0,0 +1,274 @@
+// Texture atlas export for batch runs.
+// Reticles are rendered at their canvas size and packed into a grid on
+// power-of-two PNG pages; `atlas.json` maps every sprite to its page, pixel
+// rect and UV rect (origin top-left, v down) so engines can load one texture.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use resvg::tiny_skia::{Pixmap, PixmapPaint, Transform};
+use serde::Serialize;
+
+use crate::{CrosshairConfig, generate_svg, raster};
+
+const MANIFEST_FILE: &str = "atlas.json";
+const PAGE_STEM: &str = "atlas";
+const MAX_PAGE_SIZE: u32 = 4096;
+// Transparent pixels around every sprite so filtering doesn't bleed neighbours in.
+const PADDING: u32 = 2;
+
+pub(crate) struct AtlasSprite {
+    // Manifest key; batch rows use their file stem.
+    pub(crate) name: String,
+    // (role, hex without '#'), e.g. ("rim", "FFFFFF").
+    pub(crate) colors: Vec<(String, String)>,
+    pub(crate) config: CrosshairConfig,
+}
+
+#[derive(Serialize)]
+struct Manifest {
+    uv_origin: &'static str,
+    pages: Vec<PageEntry>,
+    sprites: Vec<SpriteEntry>,
+}
+
+#[derive(Serialize)]
+struct PageEntry {
+    file: String,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Serialize)]
+struct SpriteEntry {
+    name: String,
+    colors: serde_json::Map<String, serde_json::Value>,
+    page: usize,
+    rect: PixelRect,
+    uv: UvRect,
+}
+
+#[derive(Serialize)]
+struct PixelRect {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Serialize)]
+struct UvRect {
+    u0: f64,
+    v0: f64,
+    u1: f64,
+    v1: f64,
+}
+
+// Columns and rows for `count` slots of `slot` px: as square as the page limit allows.
+fn page_grid(count: usize, slot: u32) -> (u32, u32) {
+    let max_columns = MAX_PAGE_SIZE / slot;
+    let columns = ((count as f64).sqrt().ceil() as u32).clamp(1, max_columns);
+    (columns, (count as u32).div_ceil(columns))
+}
+
+// Where one sprite lands.
+struct Placement {
+    page: usize,
+    rect: PixelRect,
+    uv: UvRect,
+}
+
+struct Layout {
+    // (width, height) of every page.
+    pages: Vec<(u32, u32)>,
+    // One per sprite, in sprite order.
+    placements: Vec<Placement>,
+}
+
+// Lays out `count` sprites of `size` px across as many pages as they need.
+fn pack(count: usize, size: u32) -> Result<Layout, String> {
+    let slot = size + 2 * PADDING;
+    if slot > MAX_PAGE_SIZE {
+        return Err(format!(
+            "{} px sprites do not fit a {} px atlas page",
+            size, MAX_PAGE_SIZE
+        ));
+    }
+    let max_side = MAX_PAGE_SIZE / slot;
+    let per_page = (max_side * max_side) as usize;
+
+    let mut pages = Vec::new();
+    let mut placements = Vec::with_capacity(count);
+    let mut remaining = count;
+    while remaining > 0 {
+        let on_page = remaining.min(per_page);
+        let (columns, rows) = page_grid(on_page, slot);
+        let width = (columns * slot).next_power_of_two();
+        let height = (rows * slot).next_power_of_two();
+        for idx in 0..on_page as u32 {
+            let (column, row) = (idx % columns, idx / columns);
+            let (x, y) = (column * slot + PADDING, row * slot + PADDING);
+            placements.push(Placement {
+                page: pages.len(),
+                rect: PixelRect {
+                    x,
+                    y,
+                    w: size,
+                    h: size,
+                },
+                uv: UvRect {
+                    u0: x as f64 / width as f64,
+                    v0: y as f64 / height as f64,
+                    u1: (x + size) as f64 / width as f64,
+                    v1: (y + size) as f64 / height as f64,
+                },
+            });
+        }
+        pages.push((width, height));
+        remaining -= on_page;
+    }
+    Ok(Layout { pages, placements })
+}
+
+// Writes `atlas-<n>.png` pages and `atlas.json` into `out_dir`; returns every file written.
+pub(crate) fn write_atlas(sprites: &[AtlasSprite], out_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let size = sprites.first().ok_or("No reticles to pack")?.config.size;
+    if sprites.iter().any(|sprite| sprite.config.size != size) {
+        return Err("Atlas sprites must share one canvas size".to_string());
+    }
+    let Layout { pages, placements } = pack(sprites.len(), size)?;
+    fs::create_dir_all(out_dir).map_err(|e| format!("Could not create folder: {}", e))?;
+
+    let mut written = Vec::new();
+    let mut manifest = Manifest {
+        uv_origin: "top_left",
+        pages: Vec::new(),
+        sprites: Vec::new(),
+    };
+
+    for (page_idx, &(width, height)) in pages.iter().enumerate() {
+        let mut page = Pixmap::new(width, height)
+            .ok_or_else(|| format!("Invalid atlas page size {}x{}", width, height))?;
+
+        for (sprite, placement) in sprites.iter().zip(placements.iter()) {
+            if placement.page != page_idx {
+                continue;
+            }
+            let pixmap = raster::rasterize_document(&generate_svg(&sprite.config), size)?;
+            page.draw_pixmap(
+                placement.rect.x as i32,
+                placement.rect.y as i32,
+                pixmap.as_ref(),
+                &PixmapPaint::default(),
+                Transform::identity(),
+                None,
+            );
+        }
+
+        let file = format!("{}-{}.png", PAGE_STEM, page_idx);
+        let path = out_dir.join(&file);
+        raster::save_png(&page, &path)?;
+        written.push(path);
+        manifest.pages.push(PageEntry {
+            file,
+            width,
+            height,
+        });
+    }
+
+    for (sprite, placement) in sprites.iter().zip(placements) {
+        manifest.sprites.push(SpriteEntry {
+            name: sprite.name.clone(),
+            colors: sprite
+                .colors
+                .iter()
+                .map(|(role, hex)| (role.clone(), format!("#{}", hex).into()))
+                .collect(),
+            page: placement.page,
+            rect: placement.rect,
+            uv: placement.uv,
+        });
+    }
+
+    let path = out_dir.join(MANIFEST_FILE);
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Manifest write failed: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Manifest write failed: {}", e))?;
+    written.push(path);
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Rect grown by the padding every sprite keeps clear around itself.
+    fn padded(rect: &PixelRect) -> (u32, u32, u32, u32) {
+        (
+            rect.x - PADDING,
+            rect.y - PADDING,
+            rect.x + rect.w + PADDING,
+            rect.y + rect.h + PADDING,
+        )
+    }
+
+    #[test]
+    fn pages_are_powers_of_two_within_the_limit() {
+        for (count, size) in [(1, 64), (7, 128), (300, 256), (5, 1024), (2, 4092)] {
+            let Layout { pages, placements } = pack(count, size).unwrap();
+            assert_eq!(placements.len(), count);
+            for &(width, height) in &pages {
+                assert!(width.is_power_of_two() && height.is_power_of_two());
+                assert!(width <= MAX_PAGE_SIZE && height <= MAX_PAGE_SIZE);
+            }
+            for placement in &placements {
+                let (_, _, right, bottom) = padded(&placement.rect);
+                let (width, height) = pages[placement.page];
+                assert!(right <= width && bottom <= height);
+            }
+        }
+        // 256 px sprites fit 15 x 15 to a page.
+        assert_eq!(pack(300, 256).unwrap().pages.len(), 2);
+    }
+
+    #[test]
+    fn padded_sprites_do_not_overlap() {
+        let placements = pack(300, 256).unwrap().placements;
+        for (idx, a) in placements.iter().enumerate() {
+            let (ax0, ay0, ax1, ay1) = padded(&a.rect);
+            for b in placements[idx + 1..].iter().filter(|b| b.page == a.page) {
+                let (bx0, by0, bx1, by1) = padded(&b.rect);
+                assert!(
+                    ax1 <= bx0 || bx1 <= ax0 || ay1 <= by0 || by1 <= ay0,
+                    "sprites at ({}, {}) and ({}, {}) overlap",
+                    a.rect.x,
+                    a.rect.y,
+                    b.rect.x,
+                    b.rect.y
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn uv_rects_match_pixel_rects() {
+        let Layout { pages, placements } = pack(10, 100).unwrap();
+        for Placement { page, rect, uv } in &placements {
+            let (width, height) = pages[*page];
+            assert_eq!(uv.u0, rect.x as f64 / width as f64);
+            assert_eq!(uv.v0, rect.y as f64 / height as f64);
+            assert_eq!(uv.u1, (rect.x + rect.w) as f64 / width as f64);
+            assert_eq!(uv.v1, (rect.y + rect.h) as f64 / height as f64);
+        }
+    }
+
+    #[test]
+    fn oversized_sprites_are_rejected() {
+        assert!(pack(1, MAX_PAGE_SIZE - 2 * PADDING).is_ok());
+        let Err(err) = pack(1, MAX_PAGE_SIZE) else {
+            panic!("4096 px sprites should not fit");
+        };
+        assert_eq!(err, "4096 px sprites do not fit a 4096 px atlas page");
+    }
+}
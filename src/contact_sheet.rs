@@ -0,0 +1,141 @@
+// Contact sheets for batch runs.
+// Every reticle a batch rendered is drawn into one grid cell with its colors
+// written underneath, so a whole batch can be reviewed from a single image.
+// Large batches are split across numbered sheets to keep each image small.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use svg::Document;
+use svg::Node;
+use svg::node::Text;
+use svg::node::element::{Element, Rectangle};
+
+use crate::{CrosshairConfig, SvgLayers, raster, reticle_layers};
+
+const SHEET_STEM: &str = "contact-sheet";
+// Reticle area per cell in pixels; the label lines sit below it.
+const CELL_SIZE: u32 = 128;
+const LINE_HEIGHT: u32 = 13;
+const PADDING: u32 = 8;
+const FONT_SIZE: u32 = 11;
+const FONT_FAMILY: &str = "DejaVu Sans Mono, Menlo, Consolas, monospace";
+// Mid grey so light rims and dark arms both stay visible.
+const BACKGROUND: &str = "#808080";
+const LABEL_COLOR: &str = "#ffffff";
+// Cells per sheet: a 20 x 20 grid is under 3000 px a side.
+const MAX_CELLS_PER_SHEET: usize = 400;
+
+pub(crate) struct SheetEntry {
+    // Caption lines under the cell, e.g. ["rim #FFFFFF", "arms #000000"].
+    pub(crate) label: Vec<String>,
+    pub(crate) config: CrosshairConfig,
+}
+
+// Near-square grid: as many columns as rows, or one more.
+fn grid(count: usize) -> (u32, u32) {
+    let columns = (count as f64).sqrt().ceil().max(1.0) as u32;
+    let rows = (count as u32).div_ceil(columns).max(1);
+    (columns, rows)
+}
+
+// The sheet and its pixel size.
+fn sheet_document(entries: &[SheetEntry]) -> (Document, u32, u32) {
+    let (columns, rows) = grid(entries.len());
+    let pitch_x = CELL_SIZE + PADDING;
+    let lines = entries.iter().map(|e| e.label.len()).max().unwrap_or(0) as u32;
+    let pitch_y = CELL_SIZE + lines * LINE_HEIGHT + PADDING;
+    let width = PADDING + columns * pitch_x;
+    let height = PADDING + rows * pitch_y;
+
+    let mut document = Document::new()
+        .set("width", width)
+        .set("height", height)
+        .set("viewBox", format!("0 0 {} {}", width, height))
+        .add(
+            Rectangle::new()
+                .set("width", width)
+                .set("height", height)
+                .set("fill", BACKGROUND),
+        );
+
+    for (idx, entry) in entries.iter().enumerate() {
+        let (column, row) = (idx as u32 % columns, idx as u32 / columns);
+        let (x, y) = (PADDING + column * pitch_x, PADDING + row * pitch_y);
+
+        // A nested viewport scales the reticle's design units into the cell.
+        let extent = entry.config.design_extent();
+        let mut cell = Element::new("svg");
+        for (key, value) in [
+            ("x", x.to_string()),
+            ("y", y.to_string()),
+            ("width", CELL_SIZE.to_string()),
+            ("height", CELL_SIZE.to_string()),
+            ("viewBox", format!("0 0 {} {}", extent, extent)),
+        ] {
+            cell.assign(key, value);
+        }
+        // Every cell gets its own id prefix so filters and gradients don't collide.
+        let SvgLayers { defs, layers } = reticle_layers(&entry.config, &format!("xh-s{}", idx));
+        if let Some(defs) = defs {
+            cell.append(defs);
+        }
+        for layer in layers {
+            cell.append(layer);
+        }
+        document = document.add(cell);
+
+        for (line_idx, line) in entry.label.iter().enumerate() {
+            let baseline = y + CELL_SIZE + (line_idx as u32 + 1) * LINE_HEIGHT - 2;
+            let mut label = Element::new("text");
+            for (key, value) in [
+                ("x", (x + CELL_SIZE / 2).to_string()),
+                ("y", baseline.to_string()),
+                ("text-anchor", "middle".to_string()),
+                ("font-family", FONT_FAMILY.to_string()),
+                ("font-size", FONT_SIZE.to_string()),
+                ("fill", LABEL_COLOR.to_string()),
+            ] {
+                label.assign(key, value);
+            }
+            label.append(Text::new(line.clone()));
+            document = document.add(label);
+        }
+    }
+
+    (document, width, height)
+}
+
+// Writes `contact-sheet.svg` and `contact-sheet.png` into `out_dir`, or
+// `contact-sheet-<n>.*` when the entries need more than one sheet.
+pub(crate) fn write_contact_sheet(
+    entries: &[SheetEntry],
+    out_dir: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    if entries.is_empty() {
+        return Err("No reticles to put on the contact sheet".to_string());
+    }
+    fs::create_dir_all(out_dir).map_err(|e| format!("Could not create folder: {}", e))?;
+
+    let pages: Vec<&[SheetEntry]> = entries.chunks(MAX_CELLS_PER_SHEET).collect();
+    let options = raster::text_options();
+    let mut written = Vec::new();
+    for (idx, page) in pages.iter().enumerate() {
+        let stem = if pages.len() == 1 {
+            SHEET_STEM.to_string()
+        } else {
+            format!("{}-{}", SHEET_STEM, idx + 1)
+        };
+        let (document, width, height) = sheet_document(page);
+        let svg_path = out_dir.join(format!("{}.svg", stem));
+        svg::save(&svg_path, &document).map_err(|e| format!("Save failed: {}", e))?;
+
+        let png_path = out_dir.join(format!("{}.png", stem));
+        let pixmap = raster::rasterize_with_options(&document, width, height, &options)?;
+        raster::save_png(&pixmap, &png_path)?;
+
+        written.extend([svg_path, png_path]);
+    }
+
+    Ok(written)
+}
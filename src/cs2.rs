@@ -0,0 +1,589 @@
+// Counter-Strike 2 crosshair settings and `CSGO-xxxxx-...` share codes.
+// A share code is 18 bytes (checksum first) written as a 25-digit base-57
+// number, least significant digit first. CS2 lengths are read as screen pixels,
+// so the reticle is measured at its canvas size.
+
+use crate::{
+    ContrastOutline, CrosshairConfig, GeometryUnits, MAX_CANVAS_SIZE, MarkerShape, SpokeConfig,
+    SpokeStyle, same_angle,
+};
+
+const DICTIONARY: &[u8; 57] = b"ABCDEFGHJKLMNOPQRSTUVWXYZabcdefhijkmnopqrstuvwxyz23456789";
+const CODE_PREFIX: &str = "CSGO-";
+const CODE_DIGITS: usize = 25;
+const PAYLOAD_BYTES: usize = 18;
+const PAYLOAD_VERSION: u8 = 1;
+
+// `cl_crosshairstyle` values that draw without movement spread.
+const STYLE_DEFAULT_STATIC: u8 = 1;
+const STYLE_CLASSIC_STATIC: u8 = 4;
+// `cl_crosshaircolor` 0..=4 are presets; 5 uses the RGB values.
+const COLOR_CUSTOM: u8 = 5;
+const PRESET_COLORS: [(u8, u8, u8); 5] = [
+    (250, 50, 50),
+    (50, 250, 50),
+    (250, 250, 50),
+    (50, 50, 250),
+    (50, 250, 250),
+];
+
+// Arms in spoke angles (0 = right, 90 = down); T style drops the top arm.
+const CLASSIC_ANGLES: [f64; 4] = [0.0, 90.0, 180.0, 270.0];
+const T_STYLE_ANGLES: [f64; 3] = [0.0, 90.0, 180.0];
+
+// Ranges the payload fields can hold.
+const MAX_GAP: f64 = 12.7;
+const MIN_GAP: f64 = -12.8;
+const MAX_LENGTH: f64 = 819.1;
+const MAX_THICKNESS: f64 = 25.5;
+const MAX_OUTLINE: f64 = 127.5;
+
+// Empty pixels kept around an imported crosshair, and the smallest canvas it gets.
+const IMPORT_MARGIN: f64 = 8.0;
+const MIN_IMPORT_SIZE: u32 = 64;
+
+// The crosshair console variables a share code carries.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Cs2Crosshair {
+    pub(crate) style: u8,
+    pub(crate) gap: f64,
+    pub(crate) length: f64,
+    pub(crate) thickness: f64,
+    pub(crate) outline_enabled: bool,
+    pub(crate) outline: f64,
+    pub(crate) color: u8,
+    pub(crate) rgb: (u8, u8, u8),
+    pub(crate) alpha_enabled: bool,
+    pub(crate) alpha: u8,
+    pub(crate) dot: bool,
+    pub(crate) t_style: bool,
+    pub(crate) follow_recoil: bool,
+    pub(crate) deployed_weapon_gap: bool,
+    pub(crate) fixed_gap: f64,
+    // Dynamic-style split settings; carried through unchanged.
+    pub(crate) split_distance: u8,
+    pub(crate) inner_split_alpha: f64,
+    pub(crate) outer_split_alpha: f64,
+    pub(crate) split_size_ratio: f64,
+}
+
+impl Default for Cs2Crosshair {
+    fn default() -> Self {
+        Self {
+            style: STYLE_CLASSIC_STATIC,
+            gap: -2.0,
+            length: 5.0,
+            thickness: 1.0,
+            outline_enabled: false,
+            outline: 1.0,
+            color: 1,
+            rgb: PRESET_COLORS[1],
+            alpha_enabled: true,
+            alpha: 255,
+            dot: false,
+            t_style: false,
+            follow_recoil: false,
+            deployed_weapon_gap: false,
+            fixed_gap: 3.0,
+            split_distance: 7,
+            inner_split_alpha: 0.0,
+            outer_split_alpha: 1.0,
+            split_size_ratio: 1.0,
+        }
+    }
+}
+
+// ------------------------------------------------------------
+// SHARE CODES
+// ------------------------------------------------------------
+
+fn tenths(value: f64) -> i64 {
+    (value * 10.0).round() as i64
+}
+
+fn to_payload(settings: &Cs2Crosshair) -> [u8; PAYLOAD_BYTES] {
+    let length = tenths(settings.length).clamp(0, 0x1fff) as u16;
+    let flag = |on: bool, bit: u8| if on { bit } else { 0 };
+
+    let mut bytes = [0u8; PAYLOAD_BYTES];
+    bytes[1] = PAYLOAD_VERSION;
+    bytes[2] = tenths(settings.gap).clamp(-128, 127) as i8 as u8;
+    bytes[3] = (settings.outline * 2.0).round().clamp(0.0, 255.0) as u8;
+    (bytes[4], bytes[5], bytes[6]) = settings.rgb;
+    bytes[7] = settings.alpha;
+    bytes[8] = (settings.split_distance & 0x7f) | flag(settings.follow_recoil, 0x80);
+    bytes[9] = tenths(settings.fixed_gap).clamp(-128, 127) as i8 as u8;
+    bytes[10] = (settings.color & 0x07)
+        | flag(settings.outline_enabled, 0x08)
+        | (tenths(settings.inner_split_alpha).clamp(0, 15) as u8) << 4;
+    bytes[11] = tenths(settings.outer_split_alpha).clamp(0, 15) as u8
+        | (tenths(settings.split_size_ratio).clamp(0, 15) as u8) << 4;
+    bytes[12] = tenths(settings.thickness).clamp(0, 255) as u8;
+    bytes[13] = (settings.style & 0x07) << 1
+        | flag(settings.dot, 0x10)
+        | flag(settings.deployed_weapon_gap, 0x20)
+        | flag(settings.alpha_enabled, 0x40)
+        | flag(settings.t_style, 0x80);
+    bytes[14] = (length & 0xff) as u8;
+    bytes[15] = (length >> 8) as u8;
+    bytes[0] = checksum(&bytes);
+    bytes
+}
+
+fn from_payload(bytes: &[u8; PAYLOAD_BYTES]) -> Cs2Crosshair {
+    let signed_tenths = |b: u8| b as i8 as f64 / 10.0;
+    Cs2Crosshair {
+        gap: signed_tenths(bytes[2]),
+        outline: bytes[3] as f64 / 2.0,
+        rgb: (bytes[4], bytes[5], bytes[6]),
+        alpha: bytes[7],
+        split_distance: bytes[8] & 0x7f,
+        follow_recoil: bytes[8] & 0x80 != 0,
+        fixed_gap: signed_tenths(bytes[9]),
+        color: bytes[10] & 0x07,
+        outline_enabled: bytes[10] & 0x08 != 0,
+        inner_split_alpha: (bytes[10] >> 4) as f64 / 10.0,
+        outer_split_alpha: (bytes[11] & 0x0f) as f64 / 10.0,
+        split_size_ratio: (bytes[11] >> 4) as f64 / 10.0,
+        thickness: bytes[12] as f64 / 10.0,
+        style: (bytes[13] >> 1) & 0x07,
+        dot: bytes[13] & 0x10 != 0,
+        deployed_weapon_gap: bytes[13] & 0x20 != 0,
+        alpha_enabled: bytes[13] & 0x40 != 0,
+        t_style: bytes[13] & 0x80 != 0,
+        length: (((bytes[15] & 0x1f) as u16) << 8 | bytes[14] as u16) as f64 / 10.0,
+    }
+}
+
+fn checksum(bytes: &[u8; PAYLOAD_BYTES]) -> u8 {
+    bytes[1..].iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+// Divides the big-endian number in `bytes` by `divisor` in place; returns the remainder.
+fn div_rem(bytes: &mut [u8; PAYLOAD_BYTES], divisor: u32) -> u32 {
+    let mut rem = 0u32;
+    for byte in bytes.iter_mut() {
+        let acc = rem << 8 | *byte as u32;
+        *byte = (acc / divisor) as u8;
+        rem = acc % divisor;
+    }
+    rem
+}
+
+// Multiplies the big-endian number in `bytes` by `factor` and adds `digit`.
+// Returns false when the result no longer fits.
+fn mul_add(bytes: &mut [u8; PAYLOAD_BYTES], factor: u32, digit: u32) -> bool {
+    let mut carry = digit;
+    for byte in bytes.iter_mut().rev() {
+        let acc = *byte as u32 * factor + carry;
+        *byte = (acc & 0xff) as u8;
+        carry = acc >> 8;
+    }
+    carry == 0
+}
+
+pub(crate) fn encode_share_code(settings: &Cs2Crosshair) -> String {
+    payload_to_code(&to_payload(settings))
+}
+
+fn payload_to_code(payload: &[u8; PAYLOAD_BYTES]) -> String {
+    let mut number = *payload;
+    let digits: Vec<char> = (0..CODE_DIGITS)
+        .map(|_| DICTIONARY[div_rem(&mut number, DICTIONARY.len() as u32) as usize] as char)
+        .collect();
+    let groups: Vec<String> = digits.chunks(5).map(|c| c.iter().collect()).collect();
+    format!("{}{}", CODE_PREFIX, groups.join("-"))
+}
+
+pub(crate) fn decode_share_code(code: &str) -> Result<Cs2Crosshair, String> {
+    code_to_payload(code).map(|bytes| from_payload(&bytes))
+}
+
+// The checksummed payload a share code spells out.
+fn code_to_payload(code: &str) -> Result<[u8; PAYLOAD_BYTES], String> {
+    let trimmed = code.trim();
+    let body = trimmed
+        .strip_prefix(CODE_PREFIX)
+        .ok_or_else(|| format!("Share code must start with '{}'", CODE_PREFIX))?;
+    let digits: Vec<u8> = body.bytes().filter(|b| *b != b'-').collect();
+    if digits.len() != CODE_DIGITS {
+        return Err(format!(
+            "Share code has {} characters after the prefix; expected {}",
+            digits.len(),
+            CODE_DIGITS
+        ));
+    }
+
+    let mut bytes = [0u8; PAYLOAD_BYTES];
+    for digit in digits.iter().rev() {
+        let value = DICTIONARY
+            .iter()
+            .position(|d| d == digit)
+            .ok_or_else(|| format!("Invalid share code character '{}'", *digit as char))?;
+        if !mul_add(&mut bytes, DICTIONARY.len() as u32, value as u32) {
+            return Err("Share code is out of range".to_string());
+        }
+    }
+    if bytes[0] != checksum(&bytes) {
+        return Err("Share code checksum does not match; check for typos".to_string());
+    }
+    Ok(bytes)
+}
+
+// ------------------------------------------------------------
+// CONFIG MAPPING
+// ------------------------------------------------------------
+
+// True when the spoke renders as a plain constant-width bar.
+fn is_plain_bar(style: SpokeStyle, base_width: f64, tip_width: f64) -> bool {
+    match style {
+        SpokeStyle::Rectangle { corner_radius } => corner_radius <= 0.0,
+        SpokeStyle::Tapered { bulge } => bulge == 0.0 && base_width == tip_width,
+        SpokeStyle::Razor { .. }
+        | SpokeStyle::Triangle { .. }
+        | SpokeStyle::Chevron { .. }
+        | SpokeStyle::RoundedCap { .. } => false,
+    }
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, n), v| (sum + v, n + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+fn clamped(warnings: &mut Vec<String>, name: &str, value: f64, min: f64, max: f64) -> f64 {
+    if value < min || value > max {
+        warnings.push(format!(
+            "{} {:.1} px is outside CS2's {}..{} range and was clamped",
+            name, value, min, max
+        ));
+    }
+    value.clamp(min, max)
+}
+
+// CS2 settings closest to the reticle, plus one warning per feature that is
+// dropped or approximated.
+pub(crate) fn from_config(config: &CrosshairConfig) -> (Cs2Crosshair, Vec<String>) {
+    let mut warnings = Vec::new();
+    let mut settings = Cs2Crosshair::default();
+    let px = config.size as f64 / config.design_extent();
+    let spokes = &config.spokes;
+
+    let matches_layout = |angles: &[f64]| {
+        spokes.len() == angles.len()
+            && angles
+                .iter()
+                .all(|a| spokes.iter().filter(|s| same_angle(s.angle, *a)).count() == 1)
+    };
+    settings.t_style = matches_layout(&T_STYLE_ANGLES);
+    if !settings.t_style && !matches_layout(&CLASSIC_ANGLES) {
+        warnings.push(format!(
+            "{} spokes at these angles can't be drawn; CS2 always draws right, down, left and (unless T style) up arms",
+            spokes.len()
+        ));
+    }
+
+    let tips: Vec<f64> = spokes.iter().map(|s| s.tip_radius(config)).collect();
+    let lengths: Vec<f64> = spokes
+        .iter()
+        .map(|s| crate::geometry::spoke_base_radius(config, s) - s.tip_radius(config))
+        .collect();
+    let widths: Vec<f64> = spokes.iter().map(|s| s.base_width(config)).collect();
+    let differs = |values: &[f64]| values.windows(2).any(|w| (w[0] - w[1]).abs() > 1e-6);
+    if differs(&tips) || differs(&lengths) || differs(&widths) {
+        warnings.push("Spokes differ in gap, length or width; CS2 uses one value for all arms, so they are averaged".to_string());
+    }
+
+    let gap = mean(tips.iter().copied()).unwrap_or(config.center_gap_radius) * px;
+    let length = mean(lengths.iter().map(|l| l.max(0.0))).unwrap_or(0.0) * px;
+    let thickness = mean(widths.iter().copied()).unwrap_or(config.spoke_base_width) * px;
+    settings.gap = clamped(&mut warnings, "Gap", gap, MIN_GAP, MAX_GAP);
+    settings.length = clamped(&mut warnings, "Arm length", length, 0.0, MAX_LENGTH);
+    settings.thickness = clamped(&mut warnings, "Thickness", thickness, 0.0, MAX_THICKNESS);
+
+    if spokes
+        .iter()
+        .any(|s| !is_plain_bar(s.style(config), s.base_width(config), s.tip_width(config)))
+    {
+        warnings.push(
+            "Tapered, pointed or rounded spokes are drawn as plain bars of the base width"
+                .to_string(),
+        );
+    }
+
+    let (r, g, b, a) = config.arm_color;
+    settings.color = COLOR_CUSTOM;
+    settings.rgb = (r, g, b);
+    settings.alpha_enabled = true;
+    settings.alpha = (crate::clamp_alpha(a) * 255.0).round() as u8;
+    if spokes
+        .iter()
+        .any(|s| s.color.is_some_and(|c| c != config.arm_color))
+    {
+        warnings.push("Per-spoke colors are dropped; every arm uses the arm color".to_string());
+    }
+    if config.arm_gradient.is_some() {
+        warnings.push("Arm gradients are dropped".to_string());
+    }
+
+    // CS2's outline stands in for the rim: the contrast outline when one is set,
+    // otherwise the ring the spokes anchor to, at its thickness.
+    let outline = &config.contrast_outline;
+    let rim = config
+        .rings
+        .get(config.spoke_anchor_ring)
+        .or(config.rings.first())
+        .filter(|ring| ring.thickness > 0.0 && ring.paint().3 > 0.0);
+    let rim_outline = (!outline.is_visible()).then_some(rim).flatten();
+    let (outline_width, outline_color, source) = match rim_outline {
+        Some(ring) => (ring.thickness, ring.color, "rim"),
+        None => (outline.width, outline.color, "outline"),
+    };
+    settings.outline_enabled = outline.is_visible() || rim_outline.is_some();
+    if settings.outline_enabled {
+        settings.outline = clamped(
+            &mut warnings,
+            "Outline width",
+            outline_width * px,
+            0.0,
+            MAX_OUTLINE,
+        );
+        if (outline_color.0, outline_color.1, outline_color.2) != (0, 0, 0) {
+            warnings.push(format!(
+                "CS2 outlines are always black; the {} color is dropped",
+                source
+            ));
+        }
+    }
+    if rim_outline.is_some() {
+        warnings.push("The rim is drawn as the outline around the arms".to_string());
+    }
+
+    let marker = &config.center_marker;
+    settings.dot = marker.shape != MarkerShape::None && marker.size > 0.0;
+    if settings.dot && marker.shape != MarkerShape::Dot {
+        warnings.push(format!(
+            "{} center marker is drawn as a dot",
+            marker.shape.label()
+        ));
+    }
+    if settings.dot && marker.paint() != config.arm_color {
+        warnings.push("The center dot takes the arm color in CS2".to_string());
+    }
+
+    let dropped_rings = config
+        .rings
+        .iter()
+        .filter(|ring| ring.thickness > 0.0 && ring.paint().3 > 0.0)
+        .filter(|ring| !rim_outline.is_some_and(|rim| std::ptr::eq(rim, *ring)))
+        .count();
+    if dropped_rings > 0 {
+        warnings.push(if rim_outline.is_some() {
+            "Rings other than the rim can't be drawn and are dropped".to_string()
+        } else {
+            "Rings can't be drawn and are dropped".to_string()
+        });
+    }
+    if config.blur_radius > 0.0 || config.glow_radius > 0.0 {
+        warnings.push("Blur and glow are dropped".to_string());
+    }
+    if config.animation.is_active() {
+        warnings.push("Animation is dropped; the code holds the first frame".to_string());
+    }
+
+    (settings, warnings)
+}
+
+// A reticle that draws the CS2 crosshair, plus warnings for settings that only
+// matter in game.
+pub(crate) fn to_config(settings: &Cs2Crosshair) -> (CrosshairConfig, Vec<String>) {
+    let mut warnings = Vec::new();
+    if settings.style != STYLE_CLASSIC_STATIC && settings.style != STYLE_DEFAULT_STATIC {
+        warnings.push(format!(
+            "Crosshair style {} spreads while moving; imported as static",
+            settings.style
+        ));
+    }
+    if settings.follow_recoil {
+        warnings.push("Follow recoil only applies in game".to_string());
+    }
+    if settings.gap < 0.0 {
+        warnings.push(format!(
+            "Negative gap {:.1} overlaps the arms; imported as 0",
+            settings.gap
+        ));
+    }
+
+    let gap = settings.gap.max(0.0);
+    let outline = if settings.outline_enabled {
+        settings.outline
+    } else {
+        0.0
+    };
+    let reach = gap + settings.length + outline + IMPORT_MARGIN;
+    let size = ((reach * 2.0).ceil() as u32).div_ceil(2) * 2;
+
+    let (r, g, b) = match PRESET_COLORS.get(settings.color as usize) {
+        Some(&preset) => preset,
+        None => settings.rgb,
+    };
+    let alpha = if settings.alpha_enabled {
+        settings.alpha as f32 / 255.0
+    } else {
+        1.0
+    };
+    let arm_color = (r, g, b, alpha);
+
+    let mut config = CrosshairConfig {
+        size: size.clamp(MIN_IMPORT_SIZE, MAX_CANVAS_SIZE),
+        units: GeometryUnits::Pixels,
+        rings: Vec::new(),
+        arm_color,
+        spoke_style: SpokeStyle::Rectangle { corner_radius: 0.0 },
+        spoke_base_width: settings.thickness,
+        spoke_tip_width: settings.thickness,
+        center_gap_radius: gap,
+        blur_radius: 0.0,
+        glow_radius: 0.0,
+        ..CrosshairConfig::default()
+    };
+    // Without rings spokes anchor to the canvas edge; the gap pulls the base in.
+    config.gap_from_ring = (config.design_extent() / 2.0 - gap - settings.length).max(0.0);
+    let angles: &[f64] = if settings.t_style {
+        &T_STYLE_ANGLES
+    } else {
+        &CLASSIC_ANGLES
+    };
+    config.spokes = angles.iter().copied().map(SpokeConfig::at).collect();
+    config.contrast_outline = ContrastOutline {
+        enabled: settings.outline_enabled,
+        width: settings.outline,
+        color: (0, 0, 0, 1.0),
+    };
+    if settings.dot {
+        config.center_marker.shape = MarkerShape::Dot;
+        config.center_marker.size = (settings.thickness / 2.0).max(0.5);
+        config.center_marker.color = arm_color;
+        config.center_marker.opacity = 1.0;
+    }
+    config.set_units(GeometryUnits::Design);
+
+    (config, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KNOWN_CODE: &str = "CSGO-O4Jsi-V36wY-rTMGK-9w7qF-jQ8WB";
+
+    #[test]
+    fn known_code_is_a_version_one_payload() {
+        let payload = code_to_payload(KNOWN_CODE).expect("known code decodes");
+        assert_eq!(payload[1], PAYLOAD_VERSION);
+        assert_eq!(payload[0], checksum(&payload));
+        assert_eq!(payload_to_code(&payload), KNOWN_CODE);
+    }
+
+    #[test]
+    fn known_code_round_trips() {
+        let settings = decode_share_code(KNOWN_CODE).unwrap();
+        assert_eq!(encode_share_code(&settings), KNOWN_CODE);
+        assert_eq!(
+            decode_share_code(&encode_share_code(&settings)),
+            Ok(settings)
+        );
+    }
+
+    #[test]
+    fn settings_round_trip() {
+        // Values are chosen on the payload's tenths grid so nothing is rounded.
+        let settings = Cs2Crosshair {
+            style: STYLE_DEFAULT_STATIC,
+            gap: -3.5,
+            length: 12.3,
+            thickness: 0.7,
+            outline_enabled: true,
+            outline: 1.5,
+            color: COLOR_CUSTOM,
+            rgb: (12, 200, 77),
+            alpha_enabled: true,
+            alpha: 180,
+            dot: true,
+            t_style: true,
+            follow_recoil: true,
+            deployed_weapon_gap: true,
+            fixed_gap: 2.4,
+            split_distance: 3,
+            inner_split_alpha: 0.5,
+            outer_split_alpha: 0.8,
+            split_size_ratio: 0.3,
+        };
+        let code = encode_share_code(&settings);
+        assert!(code.starts_with(CODE_PREFIX));
+        assert_eq!(code.len(), CODE_PREFIX.len() + CODE_DIGITS + 4);
+        assert_eq!(decode_share_code(&code), Ok(settings.clone()));
+        // Surrounding whitespace from a paste is ignored.
+        assert_eq!(decode_share_code(&format!("  {}\n", code)), Ok(settings));
+
+        let defaults = Cs2Crosshair::default();
+        assert_eq!(
+            decode_share_code(&encode_share_code(&defaults)),
+            Ok(defaults)
+        );
+    }
+
+    #[test]
+    fn bad_checksum_is_rejected() {
+        let mut payload = code_to_payload(KNOWN_CODE).unwrap();
+        payload[0] = payload[0].wrapping_add(1);
+        let err = decode_share_code(&payload_to_code(&payload)).unwrap_err();
+        assert!(err.contains("checksum"), "{}", err);
+    }
+
+    #[test]
+    fn malformed_codes_are_rejected() {
+        // '0' is not in the share code alphabet.
+        let invalid = KNOWN_CODE.replacen('4', "0", 1);
+        let err = decode_share_code(&invalid).unwrap_err();
+        assert!(err.contains("Invalid share code character '0'"), "{}", err);
+
+        assert!(decode_share_code("O4Jsi-V36wY-rTMGK-9w7qF-jQ8WB").is_err());
+        assert!(decode_share_code("CSGO-O4Jsi-V36wY-rTMGK-9w7qF").is_err());
+        assert!(decode_share_code("CSGO-zzzzz-zzzzz-zzzzz-zzzzz-zzzzz").is_err());
+    }
+
+    #[test]
+    fn outline_comes_from_the_rim_unless_a_contrast_outline_is_set() {
+        let mut config = CrosshairConfig::default();
+        config.rings[0].thickness = 2.0;
+        let (settings, warnings) = from_config(&config);
+        assert!(settings.outline_enabled);
+        assert!((settings.outline - 2.0).abs() < 1e-6);
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.contains("the rim color is dropped"))
+        );
+        assert!(!warnings.iter().any(|w| w.contains("Rings")));
+
+        config.contrast_outline = ContrastOutline {
+            enabled: true,
+            width: 1.0,
+            color: (0, 0, 0, 1.0),
+        };
+        let (settings, warnings) = from_config(&config);
+        assert!(settings.outline_enabled);
+        assert!((settings.outline - 1.0).abs() < 1e-6);
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w == "Rings can't be drawn and are dropped")
+        );
+
+        config.contrast_outline.enabled = false;
+        config.rings.clear();
+        let (settings, _) = from_config(&config);
+        assert!(!settings.outline_enabled);
+    }
+}
@@ -0,0 +1,201 @@
+// Static HTML gallery for batch output.
+// `index.html` carries its own CSS and script and shows each reticle from the SVG
+// or PNG the batch wrote next to it, so the folder works offline wherever it is
+// shared. Rows without such a file are inlined as a data URI instead. Backgrounds
+// switch between dark, light, checkerboard and a custom image URL.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const GALLERY_FILE: &str = "index.html";
+// WCAG AA for graphics; pairs below it are flagged on their card.
+const MIN_CONTRAST: f64 = 3.0;
+
+pub(crate) struct GalleryEntry {
+    pub(crate) name: String,
+    // Files the batch wrote for this reticle, relative to the output folder; empty
+    // when it only went into an atlas.
+    pub(crate) files: Vec<String>,
+    // (role, hex without '#'), e.g. ("rim", "FFFFFF").
+    pub(crate) colors: Vec<(String, String)>,
+    // Rim against arms, as a WCAG contrast ratio.
+    pub(crate) contrast: f64,
+    // Reticle SVG (without embedded config) for rows whose files a browser can't show.
+    pub(crate) inline_svg: Option<String>,
+}
+
+// Files the gallery can show directly with a relative `<img src>`.
+pub(crate) fn is_web_image(file: &str) -> bool {
+    file.ends_with(".svg") || file.ends_with(".png")
+}
+
+// WCAG 2 relative luminance of an sRGB color.
+fn luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    let channel = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
+// 1.0 for identical colors up to 21.0 for black on white.
+pub(crate) fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let (la, lb) = (luminance(a), luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn escape_html(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn card(entry: &GalleryEntry) -> String {
+    let files: String = if entry.files.is_empty() {
+        format!("<li>{}</li>", escape_html(&entry.name))
+    } else {
+        entry
+            .files
+            .iter()
+            .map(|file| format!("<li><a href=\"{0}\">{0}</a></li>", escape_html(file)))
+            .collect()
+    };
+    let colors: String = entry
+        .colors
+        .iter()
+        .map(|(role, hex)| {
+            format!(
+                "<li><span class=\"chip\" style=\"background:#{1}\"></span>{0} #{1}</li>",
+                escape_html(role),
+                escape_html(hex)
+            )
+        })
+        .collect();
+    // SVG is preferred over PNG: it stays sharp at the card size.
+    let linked = entry
+        .files
+        .iter()
+        .filter(|file| is_web_image(file))
+        .min_by_key(|file| !file.ends_with(".svg"));
+    let image = match (linked, &entry.inline_svg) {
+        (Some(file), _) => escape_html(file),
+        (None, Some(svg)) => format!("data:image/svg+xml;base64,{}", base64(svg.as_bytes())),
+        (None, None) => String::new(),
+    };
+    let contrast_class = if entry.contrast < MIN_CONTRAST {
+        "contrast low"
+    } else {
+        "contrast"
+    };
+
+    format!(
+        r#"<figure class="card">
+<div class="stage"><img alt="{title}" src="{image}"></div>
+<figcaption>
+<ul class="files">{files}</ul>
+<ul class="colors">{colors}</ul>
+<p class="{contrast_class}">Contrast {contrast:.2}:1</p>
+</figcaption>
+</figure>
+"#,
+        title = escape_html(&entry.name),
+        image = image,
+        files = files,
+        colors = colors,
+        contrast_class = contrast_class,
+        contrast = entry.contrast,
+    )
+}
+
+const PAGE_STYLE: &str = r#"
+body { margin: 0; font: 14px/1.4 system-ui, sans-serif; background: #f4f4f4; color: #222; --stage-image: none; --stage-size: auto; }
+header { position: sticky; top: 0; z-index: 1; display: flex; flex-wrap: wrap; gap: 12px; align-items: center; padding: 10px 16px; background: #fff; border-bottom: 1px solid #ccc; }
+header h1 { font-size: 16px; margin: 0 12px 0 0; }
+header input[type=url] { width: 22em; }
+main { display: grid; grid-template-columns: repeat(auto-fill, minmax(220px, 1fr)); gap: 16px; padding: 16px; }
+.card { margin: 0; background: #fff; border: 1px solid #ccc; border-radius: 6px; overflow: hidden; }
+.stage { display: flex; justify-content: center; padding: 16px; background-color: var(--stage-color); background-image: var(--stage-image); background-size: var(--stage-size); background-position: center; }
+.stage img { width: 160px; height: 160px; }
+figcaption { padding: 8px 12px; }
+figcaption ul { list-style: none; margin: 0 0 6px; padding: 0; }
+.files { font-family: ui-monospace, monospace; font-size: 12px; word-break: break-all; }
+.chip { display: inline-block; width: 12px; height: 12px; margin-right: 6px; vertical-align: -1px; border: 1px solid #888; }
+.contrast { margin: 0; font-weight: 600; }
+.contrast.low { color: #b00020; }
+body[data-bg=dark] { --stage-color: #1b1b1b; }
+body[data-bg=light] { --stage-color: #f0f0f0; }
+body[data-bg=checker] { --stage-color: #fff; --stage-size: 20px 20px; --stage-image: conic-gradient(#c8c8c8 25%, #fff 0 50%, #c8c8c8 0 75%, #fff 0); }
+body[data-bg=image] { --stage-color: #777; --stage-size: cover; --stage-image: var(--custom-image, none); }
+"#;
+
+const PAGE_SCRIPT: &str = r#"
+const body = document.body;
+const url = document.getElementById("bg-url");
+for (const radio of document.querySelectorAll("input[name=bg]")) {
+  radio.addEventListener("change", () => { body.dataset.bg = radio.value; });
+}
+url.addEventListener("change", () => {
+  body.style.setProperty("--custom-image", url.value ? "url(" + JSON.stringify(url.value) + ")" : "none");
+  document.getElementById("bg-image").checked = true;
+  body.dataset.bg = "image";
+});
+"#;
+
+pub(crate) fn write_gallery(entries: &[GalleryEntry], out_dir: &Path) -> Result<PathBuf, String> {
+    let cards: String = entries.iter().map(card).collect();
+    let page = format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Reticle gallery</title>
+<style>{style}</style>
+</head>
+<body data-bg="dark">
+<header>
+<h1>{count} reticles</h1>
+<label><input type="radio" name="bg" value="dark" checked> Dark</label>
+<label><input type="radio" name="bg" value="light"> Light</label>
+<label><input type="radio" name="bg" value="checker"> Checkerboard</label>
+<label><input type="radio" name="bg" value="image" id="bg-image"> Image</label>
+<input type="url" id="bg-url" placeholder="Background image URL">
+</header>
+<main>
+{cards}</main>
+<script>{script}</script>
+</body>
+</html>
+"#,
+        style = PAGE_STYLE,
+        count = entries.len(),
+        cards = cards,
+        script = PAGE_SCRIPT,
+    );
+
+    let path = out_dir.join(GALLERY_FILE);
+    fs::write(&path, page).map_err(|e| format!("Gallery write failed: {}", e))?;
+    Ok(path)
+}
@@ -0,0 +1,806 @@
+// Resolution-independent reticle geometry.
+// Every renderer (SVG writer, egui preview, raster exporters) consumes the
+// outlines built here, so a spoke or ring shape only has to be described once.
+
+use std::f64::consts::PI;
+
+use crate::{
+    CenterMarker, CrosshairConfig, MarkerShape, RingStyle, SpokeConfig, SpokeStyle, clamp_alpha,
+};
+
+pub(crate) type Point = (f64, f64);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Segment {
+    MoveTo(Point),
+    LineTo(Point),
+    // Control point, end point.
+    QuadTo(Point, Point),
+    Close,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Outline {
+    pub(crate) segments: Vec<Segment>,
+}
+
+impl Outline {
+    fn move_to(&mut self, p: Point) {
+        self.segments.push(Segment::MoveTo(p));
+    }
+
+    fn line_to(&mut self, p: Point) {
+        self.segments.push(Segment::LineTo(p));
+    }
+
+    fn quad_to(&mut self, ctrl: Point, end: Point) {
+        self.segments.push(Segment::QuadTo(ctrl, end));
+    }
+
+    fn close(&mut self) {
+        self.segments.push(Segment::Close);
+    }
+
+    // Samples each quad with `steps` points and returns one polyline per subpath.
+    pub(crate) fn flatten(&self, steps: usize) -> Vec<Vec<Point>> {
+        let mut subpaths = Vec::new();
+        let mut current: Vec<Point> = Vec::new();
+
+        for segment in &self.segments {
+            match *segment {
+                Segment::MoveTo(p) => {
+                    if current.len() > 1 {
+                        subpaths.push(std::mem::take(&mut current));
+                    }
+                    current.clear();
+                    current.push(p);
+                }
+                Segment::LineTo(p) => current.push(p),
+                Segment::QuadTo(ctrl, end) => {
+                    let start = match current.last().copied() {
+                        Some(p) => p,
+                        None => continue,
+                    };
+                    for i in 1..=steps {
+                        let t = i as f64 / steps as f64;
+                        let omt = 1.0 - t;
+                        let x = omt * omt * start.0 + 2.0 * omt * t * ctrl.0 + t * t * end.0;
+                        let y = omt * omt * start.1 + 2.0 * omt * t * ctrl.1 + t * t * end.1;
+                        current.push((x, y));
+                    }
+                }
+                Segment::Close => {
+                    if current.len() > 1 {
+                        subpaths.push(std::mem::take(&mut current));
+                    }
+                }
+            }
+        }
+
+        if current.len() > 1 {
+            subpaths.push(current);
+        }
+        subpaths
+    }
+}
+
+// ------------------------------------------------------------
+// RADII
+// ------------------------------------------------------------
+
+// Inner edge of the ring spokes anchor to; without rings, spokes reach the canvas edge.
+// All radii are in the config's design units.
+pub(crate) fn anchor_radius(config: &CrosshairConfig) -> f64 {
+    config
+        .rings
+        .get(config.spoke_anchor_ring)
+        .or(config.rings.first())
+        .map(|ring| ring.inner_radius())
+        .unwrap_or(config.design_extent() / 2.0)
+}
+
+pub(crate) fn spoke_base_radius(config: &CrosshairConfig, spoke: &SpokeConfig) -> f64 {
+    (anchor_radius(config) - spoke.gap_from_ring(config)).max(0.0)
+}
+
+pub(crate) fn spoke_tip_radius(config: &CrosshairConfig, spoke: &SpokeConfig) -> f64 {
+    spoke.tip_radius(config).max(0.0)
+}
+
+// ------------------------------------------------------------
+// SPOKES
+// ------------------------------------------------------------
+
+// Razor taper stations as (fraction of the base->tip distance, fraction of the base half width):
+// wide shoulder -> slimmer mid, then the style's pinch just before the sides meet at the tip.
+const RAZOR_STATIONS: [(f64, f64); 2] = [(0.25, 0.9), (0.6, 0.6)];
+const RAZOR_PINCH_AT: f64 = 0.9;
+
+pub(crate) struct SpokeSpec {
+    pub(crate) angle_deg: f64,
+    pub(crate) tip_r: f64,
+    pub(crate) base_r: f64,
+    pub(crate) base_width: f64,
+    pub(crate) tip_width: f64,
+    pub(crate) style: SpokeStyle,
+}
+
+// Local frame of one spoke: `along(r)` walks the spoke axis, `right`/`left` step
+// sideways from a point on it.
+struct SpokeFrame {
+    center: Point,
+    axis: Point,
+    side: Point,
+    angle: f64,
+}
+
+impl SpokeFrame {
+    fn new(center: Point, angle_deg: f64) -> Self {
+        let angle = angle_deg.to_radians();
+        let (ux, uy) = (angle.cos(), angle.sin());
+        Self {
+            center,
+            axis: (ux, uy),
+            side: (-uy, ux),
+            angle,
+        }
+    }
+
+    fn along(&self, r: f64) -> Point {
+        (
+            self.center.0 + r * self.axis.0,
+            self.center.1 + r * self.axis.1,
+        )
+    }
+
+    fn right(&self, p: Point, half: f64) -> Point {
+        (p.0 + self.side.0 * half, p.1 + self.side.1 * half)
+    }
+
+    fn left(&self, p: Point, half: f64) -> Point {
+        self.right(p, -half)
+    }
+}
+
+fn mid(a: Point, b: Point) -> Point {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+pub(crate) fn spoke_outline(center: Point, spec: &SpokeSpec) -> Outline {
+    let frame = SpokeFrame::new(center, spec.angle_deg);
+    let base_half = spec.base_width.max(0.0) / 2.0;
+    let tip_half = spec.tip_width.max(0.0) / 2.0;
+
+    let mut outline = Outline::default();
+    match spec.style {
+        SpokeStyle::Tapered { bulge } => {
+            tapered_spoke(&mut outline, &frame, spec, base_half, tip_half, bulge)
+        }
+        SpokeStyle::Razor { pinch } => razor_spoke(&mut outline, &frame, spec, base_half, pinch),
+        SpokeStyle::Rectangle { corner_radius } => {
+            let (base, tip) = (frame.along(spec.base_r), frame.along(spec.tip_r));
+            let corners = [
+                frame.left(base, base_half),
+                frame.right(base, base_half),
+                frame.right(tip, base_half),
+                frame.left(tip, base_half),
+            ];
+            add_rounded_polygon(&mut outline, &corners, corner_radius);
+        }
+        SpokeStyle::Triangle { inverted } => {
+            let (wide, point) = if inverted {
+                (frame.along(spec.tip_r), frame.along(spec.base_r))
+            } else {
+                (frame.along(spec.base_r), frame.along(spec.tip_r))
+            };
+            add_polygon(
+                &mut outline,
+                &[
+                    frame.left(wide, base_half),
+                    frame.right(wide, base_half),
+                    point,
+                ],
+            );
+        }
+        SpokeStyle::Chevron { thickness } => {
+            chevron_spoke(&mut outline, &frame, spec, base_half, thickness)
+        }
+        SpokeStyle::RoundedCap { round_base } => {
+            rounded_spoke(&mut outline, &frame, spec, base_half, round_base)
+        }
+    }
+    outline
+}
+
+fn tapered_spoke(
+    outline: &mut Outline,
+    frame: &SpokeFrame,
+    spec: &SpokeSpec,
+    base_half: f64,
+    tip_half: f64,
+    bulge: f64,
+) {
+    let (base, tip) = (frame.along(spec.base_r), frame.along(spec.tip_r));
+    let (bl, br) = (frame.left(base, base_half), frame.right(base, base_half));
+    let (tl, tr) = (frame.left(tip, tip_half), frame.right(tip, tip_half));
+    let push = bulge * base_half * 2.0;
+
+    outline.move_to(bl);
+    outline.line_to(br);
+    outline.quad_to(frame.right(mid(br, tr), push), tr);
+    outline.line_to(tl);
+    outline.quad_to(frame.left(mid(bl, tl), push), bl);
+    outline.close();
+}
+
+fn razor_spoke(
+    outline: &mut Outline,
+    frame: &SpokeFrame,
+    spec: &SpokeSpec,
+    base_half: f64,
+    pinch: f64,
+) {
+    let (base, tip) = (frame.along(spec.base_r), frame.along(spec.tip_r));
+    let (bl, br) = (frame.left(base, base_half), frame.right(base, base_half));
+    let dist = spec.base_r - spec.tip_r;
+    let stations: Vec<(Point, f64)> = RAZOR_STATIONS
+        .iter()
+        .copied()
+        .chain([(RAZOR_PINCH_AT, pinch.max(0.0))])
+        .map(|(r_frac, half_frac)| {
+            (
+                frame.along(spec.base_r - dist * r_frac),
+                base_half * half_frac,
+            )
+        })
+        .collect();
+
+    let rights: Vec<Point> = stations.iter().map(|&(p, h)| frame.right(p, h)).collect();
+    let lefts: Vec<Point> = stations.iter().map(|&(p, h)| frame.left(p, h)).collect();
+
+    outline.move_to(bl);
+    outline.line_to(br);
+    outline.line_to(rights[0]);
+    for pair in rights.windows(2) {
+        outline.quad_to(mid(pair[0], pair[1]), pair[1]);
+    }
+    let pinch_r = rights[rights.len() - 1];
+    outline.quad_to(mid(pinch_r, tip), tip);
+    let pinch_l = lefts[lefts.len() - 1];
+    outline.quad_to(mid(pinch_l, tip), pinch_l);
+    for pair in lefts.windows(2).rev() {
+        outline.quad_to(mid(pair[0], pair[1]), pair[0]);
+    }
+    outline.line_to(bl);
+    outline.close();
+}
+
+// A V whose outer edges run from the tip to the base corners; the inner edges are
+// the same V shifted toward the base so each arm is `thickness` wide.
+fn chevron_spoke(
+    outline: &mut Outline,
+    frame: &SpokeFrame,
+    spec: &SpokeSpec,
+    base_half: f64,
+    thickness: f64,
+) {
+    let (base, tip) = (frame.along(spec.base_r), frame.along(spec.tip_r));
+    let (bl, br) = (frame.left(base, base_half), frame.right(base, base_half));
+    let len = (spec.base_r - spec.tip_r).abs();
+    let arm_len = (len * len + base_half * base_half).sqrt();
+    if base_half <= 0.0 || arm_len <= 0.0 {
+        return;
+    }
+
+    // Axial shift that moves each arm `thickness` along its normal.
+    let shift = thickness.max(0.0) * arm_len / base_half;
+    if shift >= len {
+        add_polygon(outline, &[bl, br, tip]);
+        return;
+    }
+
+    let toward_base = (spec.base_r - spec.tip_r).signum();
+    let notch = frame.along(spec.tip_r + toward_base * shift);
+    let inner_half = base_half * (len - shift) / len;
+    add_polygon(
+        outline,
+        &[
+            bl,
+            frame.left(base, inner_half),
+            notch,
+            frame.right(base, inner_half),
+            br,
+            tip,
+        ],
+    );
+}
+
+// Stadium-style line: the rounded tip ends exactly at the tip radius.
+fn rounded_spoke(
+    outline: &mut Outline,
+    frame: &SpokeFrame,
+    spec: &SpokeSpec,
+    half: f64,
+    round_base: bool,
+) {
+    let toward_tip = (spec.tip_r - spec.base_r).signum();
+    let len = (spec.base_r - spec.tip_r).abs();
+    if half <= 0.0 {
+        return;
+    }
+    let caps = if round_base { 2.0 } else { 1.0 };
+    let radius = half.min(len / caps);
+    if radius <= 0.0 {
+        return;
+    }
+
+    let tip_c = frame.along(spec.tip_r - toward_tip * radius);
+    let base_c = if round_base {
+        frame.along(spec.base_r + toward_tip * radius)
+    } else {
+        frame.along(spec.base_r)
+    };
+
+    // Caps sweep from one side through the end direction to the other side.
+    let right_side = frame.angle + PI / 2.0;
+    let left_side = frame.angle - PI / 2.0;
+    let sweep = -toward_tip * PI;
+
+    outline.move_to(frame.right(base_c, radius));
+    add_arc(
+        outline,
+        tip_c,
+        radius,
+        right_side,
+        right_side + sweep,
+        false,
+    );
+    if round_base {
+        add_arc(outline, base_c, radius, left_side, left_side + sweep, false);
+    } else {
+        outline.line_to(frame.left(base_c, radius));
+    }
+    outline.close();
+}
+
+// ------------------------------------------------------------
+// RINGS
+// ------------------------------------------------------------
+
+// Maximum radial deviation of the quad-approximated circle, in config units.
+const CIRCLE_TOLERANCE: f64 = 0.01;
+
+fn circle_segment_count(radius: f64) -> usize {
+    // A quad spanning 2a radians deviates from the true arc by about r * a^4 / 8.
+    let half_span = (8.0 * CIRCLE_TOLERANCE / radius.max(1.0)).powf(0.25);
+    ((PI / half_span).ceil() as usize).max(8)
+}
+
+// Traces the arc from `start` to `end` (radians, either direction) with quads.
+// Starts a new subpath when `begin` is set, otherwise connects with a line.
+fn add_arc(outline: &mut Outline, center: Point, radius: f64, start: f64, end: f64, begin: bool) {
+    let sweep = end - start;
+    let segments =
+        ((circle_segment_count(radius) as f64 * sweep.abs() / (2.0 * PI)).ceil() as usize).max(1);
+    let step = sweep / segments as f64;
+    let ctrl_r = radius / (step / 2.0).cos();
+    let at = |r: f64, a: f64| (center.0 + r * a.cos(), center.1 + r * a.sin());
+
+    if begin {
+        outline.move_to(at(radius, start));
+    } else {
+        outline.line_to(at(radius, start));
+    }
+    for i in 0..segments {
+        let a0 = start + step * i as f64;
+        outline.quad_to(at(ctrl_r, a0 + step / 2.0), at(radius, a0 + step));
+    }
+}
+
+// Annulus as an outer loop plus a reversed inner loop; fill with even-odd (or nonzero).
+pub(crate) fn ring_outline(center: Point, outer_r: f64, inner_r: f64) -> Outline {
+    let mut outline = Outline::default();
+    if outer_r <= 0.0 {
+        return outline;
+    }
+
+    add_arc(&mut outline, center, outer_r, 0.0, 2.0 * PI, true);
+    outline.close();
+    if inner_r > 0.0 && inner_r < outer_r {
+        add_arc(&mut outline, center, inner_r, 0.0, -2.0 * PI, true);
+        outline.close();
+    }
+    outline
+}
+
+// Closed annular sector between `start_deg` and `end_deg`, measured like spoke angles.
+fn add_ring_sector(
+    outline: &mut Outline,
+    center: Point,
+    outer_r: f64,
+    inner_r: f64,
+    start_deg: f64,
+    end_deg: f64,
+) {
+    let (start, end) = (start_deg.to_radians(), end_deg.to_radians());
+    add_arc(outline, center, outer_r, start, end, true);
+    if inner_r > 0.0 {
+        add_arc(outline, center, inner_r, end, start, false);
+    } else {
+        outline.line_to(center);
+    }
+    outline.close();
+}
+
+// Arc spans (start, end) in degrees for a ring style, or None for a full ring.
+pub(crate) fn ring_arc_spans(style: &RingStyle, spoke_angles: &[f64]) -> Option<Vec<(f64, f64)>> {
+    match *style {
+        RingStyle::Full => None,
+        RingStyle::Segmented {
+            start_angle,
+            end_angle,
+            count,
+            gap,
+        } => {
+            let mut span = end_angle - start_angle;
+            if span <= 0.0 {
+                span += 360.0;
+            }
+            let span = span.min(360.0);
+            let count = count.max(1) as f64;
+            // A closed sweep also needs a gap between the last and first arc.
+            let gaps = if span >= 360.0 { count } else { count - 1.0 };
+            let arc = (span - gap.max(0.0) * gaps) / count;
+            if arc <= 0.0 {
+                return Some(Vec::new());
+            }
+            Some(
+                (0..count as usize)
+                    .map(|i| {
+                        let a0 = start_angle + i as f64 * (arc + gap.max(0.0));
+                        (a0, a0 + arc)
+                    })
+                    .collect(),
+            )
+        }
+        RingStyle::SpokeGaps { gap } => {
+            let mut angles: Vec<f64> = spoke_angles.iter().map(|a| a.rem_euclid(360.0)).collect();
+            if angles.is_empty() {
+                return None;
+            }
+            angles.sort_by(f64::total_cmp);
+            angles.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+
+            let half_gap = gap.max(0.0) / 2.0;
+            Some(
+                (0..angles.len())
+                    .filter_map(|i| {
+                        let from = angles[i];
+                        let mut to = angles[(i + 1) % angles.len()];
+                        if to <= from {
+                            to += 360.0;
+                        }
+                        let (a0, a1) = (from + half_gap, to - half_gap);
+                        (a1 > a0).then_some((a0, a1))
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+pub(crate) fn styled_ring_outline(
+    center: Point,
+    outer_r: f64,
+    inner_r: f64,
+    style: &RingStyle,
+    spoke_angles: &[f64],
+) -> Outline {
+    let Some(spans) = ring_arc_spans(style, spoke_angles) else {
+        return ring_outline(center, outer_r, inner_r);
+    };
+
+    let mut outline = Outline::default();
+    if outer_r <= 0.0 {
+        return outline;
+    }
+    for (start, end) in spans {
+        add_ring_sector(&mut outline, center, outer_r, inner_r, start, end);
+    }
+    outline
+}
+
+// ------------------------------------------------------------
+// CENTER MARKER
+// ------------------------------------------------------------
+
+fn add_polygon(outline: &mut Outline, points: &[Point]) {
+    let Some((&first, rest)) = points.split_first() else {
+        return;
+    };
+    outline.move_to(first);
+    for &p in rest {
+        outline.line_to(p);
+    }
+    outline.close();
+}
+
+// Closed polygon whose corners are replaced by quads of up to `radius`; each corner
+// is limited to half of its shorter adjacent edge.
+fn add_rounded_polygon(outline: &mut Outline, points: &[Point], radius: f64) {
+    if radius <= 0.0 || points.len() < 3 {
+        add_polygon(outline, points);
+        return;
+    }
+
+    let n = points.len();
+    for i in 0..n {
+        let (prev, corner, next) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+        let len_in = (corner.0 - prev.0).hypot(corner.1 - prev.1);
+        let len_out = (next.0 - corner.0).hypot(next.1 - corner.1);
+        let r = radius.min(len_in / 2.0).min(len_out / 2.0);
+        let toward = |from: Point, to: Point, len: f64, d: f64| {
+            if len <= 0.0 {
+                from
+            } else {
+                (
+                    from.0 + (to.0 - from.0) * d / len,
+                    from.1 + (to.1 - from.1) * d / len,
+                )
+            }
+        };
+
+        let start = toward(corner, prev, len_in, r);
+        let end = toward(corner, next, len_out, r);
+        if i == 0 {
+            outline.move_to(start);
+        } else {
+            outline.line_to(start);
+        }
+        outline.quad_to(corner, end);
+    }
+    outline.close();
+}
+
+// `size` is the marker radius (half extent); `thickness` is the line weight of
+// the hollow circle, plus and chevron.
+pub(crate) fn marker_outline(center: Point, marker: &CenterMarker) -> Option<Outline> {
+    let size = marker.size.max(0.0);
+    let t = marker.thickness.clamp(0.0, size * 2.0);
+    if size <= 0.0 {
+        return None;
+    }
+    let (cx, cy) = center;
+
+    let mut outline = Outline::default();
+    match marker.shape {
+        MarkerShape::None => return None,
+        MarkerShape::Dot => return Some(ring_outline(center, size, 0.0)),
+        MarkerShape::Circle => return Some(ring_outline(center, size, (size - t).max(0.0))),
+        MarkerShape::Plus => {
+            let h = t / 2.0;
+            add_polygon(
+                &mut outline,
+                &[
+                    (cx - h, cy - size),
+                    (cx + h, cy - size),
+                    (cx + h, cy - h),
+                    (cx + size, cy - h),
+                    (cx + size, cy + h),
+                    (cx + h, cy + h),
+                    (cx + h, cy + size),
+                    (cx - h, cy + size),
+                    (cx - h, cy + h),
+                    (cx - size, cy + h),
+                    (cx - size, cy - h),
+                    (cx - h, cy - h),
+                ],
+            );
+        }
+        MarkerShape::Chevron => {
+            // Upward-pointing chevron with its apex on the center; 45° arms are
+            // `t` thick when offset vertically by t * sqrt(2).
+            let d = t * std::f64::consts::SQRT_2;
+            add_polygon(
+                &mut outline,
+                &[
+                    (cx - size, cy + size),
+                    (cx, cy),
+                    (cx + size, cy + size),
+                    (cx + size, cy + size + d),
+                    (cx, cy + d),
+                    (cx - size, cy + size + d),
+                ],
+            );
+        }
+    }
+    Some(outline)
+}
+
+// ------------------------------------------------------------
+// RETICLE
+// ------------------------------------------------------------
+
+// Where a gradient runs in canvas space. `color` on the outline is the start color.
+#[derive(Clone, Copy)]
+pub(crate) enum GradientSpan {
+    // Along a spoke, from base to tip.
+    Linear {
+        from: Point,
+        to: Point,
+        end_color: (u8, u8, u8, f32),
+    },
+    // Across a ring, from its inner edge to its outer edge.
+    Radial {
+        center: Point,
+        inner_r: f64,
+        outer_r: f64,
+        end_color: (u8, u8, u8, f32),
+    },
+}
+
+pub(crate) struct FilledOutline {
+    pub(crate) outline: Outline,
+    pub(crate) color: (u8, u8, u8, f32),
+    pub(crate) gradient: Option<GradientSpan>,
+}
+
+impl FilledOutline {
+    fn solid(outline: Outline, color: (u8, u8, u8, f32)) -> Self {
+        Self {
+            outline,
+            color,
+            gradient: None,
+        }
+    }
+
+    // Paint at a canvas point, matching how SVG pads gradients beyond their ends.
+    pub(crate) fn color_at(&self, p: Point) -> (u8, u8, u8, f32) {
+        let (t, end_color) = match self.gradient {
+            None => return self.color,
+            Some(GradientSpan::Linear {
+                from,
+                to,
+                end_color,
+            }) => {
+                let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+                let len_sq = dx * dx + dy * dy;
+                if len_sq <= f64::EPSILON {
+                    return self.color;
+                }
+                (
+                    ((p.0 - from.0) * dx + (p.1 - from.1) * dy) / len_sq,
+                    end_color,
+                )
+            }
+            Some(GradientSpan::Radial {
+                center,
+                inner_r,
+                outer_r,
+                end_color,
+            }) => {
+                if outer_r - inner_r <= f64::EPSILON {
+                    return self.color;
+                }
+                let r = ((p.0 - center.0).powi(2) + (p.1 - center.1).powi(2)).sqrt();
+                ((r - inner_r) / (outer_r - inner_r), end_color)
+            }
+        };
+        lerp_color(self.color, end_color, t.clamp(0.0, 1.0))
+    }
+}
+
+fn lerp_color(a: (u8, u8, u8, f32), b: (u8, u8, u8, f32), t: f64) -> (u8, u8, u8, f32) {
+    let channel = |x: u8, y: u8| (x as f64 + (y as f64 - x as f64) * t).round() as u8;
+    (
+        channel(a.0, b.0),
+        channel(a.1, b.1),
+        channel(a.2, b.2),
+        a.3 + (b.3 - a.3) * t as f32,
+    )
+}
+
+pub(crate) struct ReticleGeometry {
+    pub(crate) spokes: Vec<FilledOutline>,
+    pub(crate) rings: Vec<FilledOutline>,
+    pub(crate) marker: Option<FilledOutline>,
+}
+
+pub(crate) fn canvas_center(config: &CrosshairConfig) -> Point {
+    let c = config.design_extent() / 2.0;
+    (c, c)
+}
+
+pub(crate) fn build_geometry(config: &CrosshairConfig) -> ReticleGeometry {
+    let center = canvas_center(config);
+
+    let spokes = config
+        .spokes
+        .iter()
+        .map(|spoke| {
+            let spec = SpokeSpec {
+                angle_deg: spoke.angle,
+                tip_r: spoke_tip_radius(config, spoke),
+                base_r: spoke_base_radius(config, spoke),
+                base_width: spoke.base_width(config),
+                tip_width: spoke.tip_width(config),
+                style: spoke.style(config),
+            };
+            let color = spoke.color(config);
+            let gradient = config.arm_gradient.as_ref().map(|gradient| {
+                let (ux, uy) = (
+                    spec.angle_deg.to_radians().cos(),
+                    spec.angle_deg.to_radians().sin(),
+                );
+                GradientSpan::Linear {
+                    from: (center.0 + spec.base_r * ux, center.1 + spec.base_r * uy),
+                    to: (center.0 + spec.tip_r * ux, center.1 + spec.tip_r * uy),
+                    end_color: gradient.end_color(color),
+                }
+            });
+            FilledOutline {
+                outline: spoke_outline(center, &spec),
+                color,
+                gradient,
+            }
+        })
+        .collect();
+
+    let spoke_angles: Vec<f64> = config.spokes.iter().map(|spoke| spoke.angle).collect();
+    let rings = config
+        .rings
+        .iter()
+        .map(|ring| {
+            let color = ring.paint();
+            let outer_r = ring.outer_radius.max(0.0);
+            let inner_r = ring.inner_radius();
+            FilledOutline {
+                outline: styled_ring_outline(center, outer_r, inner_r, &ring.style, &spoke_angles),
+                color,
+                gradient: ring.gradient.as_ref().map(|gradient| GradientSpan::Radial {
+                    center,
+                    inner_r,
+                    outer_r,
+                    // The start stop already carries the ring opacity; fade the end with it.
+                    end_color: {
+                        let (r, g, b, a) = gradient.end_color(color);
+                        (r, g, b, clamp_alpha(a * ring.opacity))
+                    },
+                }),
+            }
+        })
+        .collect();
+
+    let marker = marker_outline(center, &config.center_marker)
+        .map(|outline| FilledOutline::solid(outline, config.center_marker.paint()));
+
+    ReticleGeometry {
+        spokes,
+        rings,
+        marker,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GradientFill;
+
+    #[test]
+    fn ring_opacity_scales_both_gradient_stops() {
+        let mut config = CrosshairConfig::default();
+        config.rings[0].color = (255, 255, 255, 1.0);
+        config.rings[0].opacity = 0.5;
+        config.rings[0].gradient = Some(GradientFill {
+            end_rgb: Some((255, 0, 0)),
+            end_alpha: 0.8,
+        });
+
+        let ring = &build_geometry(&config).rings[0];
+        assert_eq!(ring.color, (255, 255, 255, 0.5));
+        let Some(GradientSpan::Radial { end_color, .. }) = ring.gradient else {
+            panic!("ring should have a radial gradient");
+        };
+        assert_eq!(end_color, (255, 0, 0, 0.4));
+    }
+}
@@ -0,0 +1,232 @@
+// Pixel-grid hinting for small renders.
+// At icon sizes a ring edge that falls halfway across a pixel turns into a grey
+// smear, so before rendering at a given size the radii and widths are nudged
+// (in design units) until the edges of axis-aligned shapes land on whole pixels.
+
+use crate::geometry::anchor_radius;
+use crate::{CrosshairConfig, GeometryUnits, MAX_CANVAS_SIZE, SpokeStyle};
+
+// Smallest size the size-set and cursor exports render; below the editor's canvas
+// minimum because these are the sizes hinting exists for.
+pub(crate) const MIN_EXPORT_SIZE: u32 = 16;
+
+// Converts between design units and output pixels for one target size.
+struct PixelGrid {
+    px_per_unit: f64,
+    // Canvas center in pixels; a whole number for even sizes, x.5 for odd ones.
+    center_px: f64,
+}
+
+impl PixelGrid {
+    fn new(config: &CrosshairConfig) -> Self {
+        Self {
+            px_per_unit: config.size as f64 / config.design_extent(),
+            center_px: config.size as f64 / 2.0,
+        }
+    }
+
+    // Radius whose circle crosses the axes on a pixel boundary.
+    fn radius(&self, r: f64) -> f64 {
+        let edge = (self.center_px + r * self.px_per_unit).round();
+        ((edge - self.center_px) / self.px_per_unit).max(0.0)
+    }
+
+    // Width centered on an axis whose two edges sit on pixel boundaries; never
+    // thinner than the narrowest width the grid allows.
+    fn width(&self, w: f64) -> f64 {
+        if w <= 0.0 {
+            return 0.0;
+        }
+        let mut half_px = self.center_px - (self.center_px - w * self.px_per_unit / 2.0).round();
+        while half_px <= 0.0 {
+            half_px += 1.0;
+        }
+        2.0 * half_px / self.px_per_unit
+    }
+
+    // Gap inside `anchor` that puts the spoke base on a pixel boundary.
+    fn gap(&self, anchor: f64, gap: f64) -> f64 {
+        anchor - self.radius(anchor - gap)
+    }
+
+    // Whole-pixel stroke, at least one pixel.
+    fn stroke(&self, w: f64) -> f64 {
+        if w <= 0.0 {
+            return 0.0;
+        }
+        (w * self.px_per_unit).round().max(1.0) / self.px_per_unit
+    }
+}
+
+// The config re-targeted to `size` pixels, in design units so proportions are kept.
+// With `snap` set, lengths are hinted to that size's pixel grid.
+pub(crate) fn config_for_size(config: &CrosshairConfig, size: u32, snap: bool) -> CrosshairConfig {
+    let mut sized = config.clone();
+    sized.set_units(GeometryUnits::Design);
+    sized.size = size;
+    if snap {
+        snap_to_grid(&mut sized);
+    }
+    sized
+}
+
+fn snap_to_grid(config: &mut CrosshairConfig) {
+    let grid = PixelGrid::new(config);
+
+    for ring in &mut config.rings {
+        let outer = grid.radius(ring.outer_radius);
+        let mut inner = grid.radius(ring.inner_radius());
+        // Snapping both edges to the same pixel would erase a thin ring.
+        if ring.thickness > 0.0 && outer - inner < grid.stroke(ring.thickness) {
+            inner = (outer - grid.stroke(ring.thickness)).max(0.0);
+        }
+        ring.outer_radius = outer;
+        ring.thickness = outer - inner;
+    }
+
+    // Spoke bases sit `gap` inside the (already snapped) anchor ring; snap where the
+    // base lands rather than the gap itself.
+    let anchor = anchor_radius(config);
+    config.gap_from_ring = grid.gap(anchor, config.gap_from_ring);
+    config.center_gap_radius = grid.radius(config.center_gap_radius);
+    config.spoke_base_width = grid.width(config.spoke_base_width);
+    config.spoke_tip_width = grid.width(config.spoke_tip_width);
+    snap_style(&grid, &mut config.spoke_style);
+
+    for spoke in &mut config.spokes {
+        spoke.gap_from_ring = spoke.gap_from_ring.map(|gap| grid.gap(anchor, gap));
+        spoke.tip_radius = spoke.tip_radius.map(|r| grid.radius(r));
+        spoke.base_width = spoke.base_width.map(|w| grid.width(w));
+        spoke.tip_width = spoke.tip_width.map(|w| grid.width(w));
+        if let Some(style) = spoke.style.as_mut() {
+            snap_style(&grid, style);
+        }
+    }
+
+    let marker = &mut config.center_marker;
+    marker.size = grid.radius(marker.size);
+    marker.thickness = grid.stroke(marker.thickness);
+    config.contrast_outline.width = grid.stroke(config.contrast_outline.width);
+}
+
+fn snap_style(grid: &PixelGrid, style: &mut SpokeStyle) {
+    if let SpokeStyle::Chevron { thickness } = style {
+        *thickness = grid.stroke(*thickness);
+    }
+}
+
+// Parses "16, 24,32" into sorted, de-duplicated sizes within the export range.
+pub(crate) fn parse_size_list(raw: &str) -> Result<Vec<u32>, String> {
+    let mut sizes = raw
+        .split([',', ' '])
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            part.trim()
+                .trim_end_matches("px")
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid size '{}'", part.trim()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    sizes.sort_unstable();
+    sizes.dedup();
+    if sizes.is_empty() {
+        return Err("Enter at least one size".to_string());
+    }
+    if let Some(size) = sizes
+        .iter()
+        .find(|size| !(MIN_EXPORT_SIZE..=MAX_CANVAS_SIZE).contains(size))
+    {
+        return Err(format!(
+            "Size {} is outside {}..={}",
+            size, MIN_EXPORT_SIZE, MAX_CANVAS_SIZE
+        ));
+    }
+    Ok(sizes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{spoke_base_radius, spoke_tip_radius};
+
+    // Canvas position in pixels of a point `r` design units right of the center.
+    fn edge_px(config: &CrosshairConfig, r: f64) -> f64 {
+        config.size as f64 / 2.0 + r * config.size as f64 / config.design_extent()
+    }
+
+    fn on_pixel(px: f64) -> bool {
+        (px - px.round()).abs() < 1e-9
+    }
+
+    #[test]
+    fn snapped_edges_land_on_whole_pixels() {
+        let config = CrosshairConfig::default();
+        for size in [16, 24, 32, 48] {
+            let sized = config_for_size(&config, size, true);
+            assert_eq!(sized.size, size);
+
+            let ring = &sized.rings[0];
+            assert!(
+                on_pixel(edge_px(&sized, ring.outer_radius)),
+                "{} px outer",
+                size
+            );
+            assert!(
+                on_pixel(edge_px(&sized, ring.inner_radius())),
+                "{} px inner",
+                size
+            );
+            assert!(ring.thickness > 0.0, "{} px ring vanished", size);
+
+            let spoke = &sized.spokes[0];
+            assert!(on_pixel(edge_px(&sized, spoke_base_radius(&sized, spoke))));
+            assert!(on_pixel(edge_px(&sized, spoke_tip_radius(&sized, spoke))));
+            for width in [sized.spoke_base_width, sized.spoke_tip_width] {
+                assert!(width > 0.0);
+                assert!(on_pixel(edge_px(&sized, -width / 2.0)), "{} px width", size);
+            }
+        }
+    }
+
+    #[test]
+    fn unsnapped_sizes_keep_proportions() {
+        let config = CrosshairConfig::default();
+        let sized = config_for_size(&config, 24, false);
+        assert_eq!(sized.rings[0].outer_radius, config.rings[0].outer_radius);
+        assert_eq!(sized.design_extent(), config.design_extent());
+        // The default rim does not fall on the 24 px grid by itself.
+        assert!(!on_pixel(edge_px(&sized, sized.rings[0].outer_radius)));
+    }
+
+    #[test]
+    fn size_lists_parse_sorted_and_deduplicated() {
+        assert_eq!(parse_size_list("48, 16,32px 16"), Ok(vec![16, 32, 48]));
+        assert_eq!(
+            parse_size_list(&MIN_EXPORT_SIZE.to_string()),
+            Ok(vec![MIN_EXPORT_SIZE])
+        );
+    }
+
+    #[test]
+    fn bad_size_lists_are_rejected() {
+        assert_eq!(
+            parse_size_list(" , "),
+            Err("Enter at least one size".to_string())
+        );
+        assert_eq!(
+            parse_size_list(""),
+            Err("Enter at least one size".to_string())
+        );
+        assert_eq!(
+            parse_size_list("16, big"),
+            Err("Invalid size 'big'".to_string())
+        );
+        assert_eq!(
+            parse_size_list("-16"),
+            Err("Invalid size '-16'".to_string())
+        );
+        let too_small = parse_size_list(&format!("{}, 32", MIN_EXPORT_SIZE - 1)).unwrap_err();
+        assert!(too_small.starts_with("Size 15 is outside"), "{}", too_small);
+        assert!(parse_size_list(&(MAX_CANVAS_SIZE + 1).to_string()).is_err());
+    }
+}
@@ -2,6 +2,20 @@
 // The GUI exposes every setting with a live preview and can still batch
 // render SVGs from the CSV color pairs used by the original CLI.
 
+mod animation;
+mod atlas;
+mod contact_sheet;
+mod cs2;
+mod gallery;
+mod geometry;
+mod hinting;
+mod metadata;
+mod raster;
+mod tessellate;
+mod validate;
+mod winicon;
+mod xcursor;
+
 use std::env;
 use std::fs;
 use std::io;
@@ -12,11 +26,19 @@ use eframe::egui::{self, Color32, IconData, Pos2, Rgba, Stroke, color_picker, po
 use eframe::icon_data;
 use serde::{Deserialize, Serialize};
 use svg::Document;
-use svg::node::element::Circle;
+use svg::Node;
+use svg::node::element::Definitions;
+use svg::node::element::Element;
+use svg::node::element::Filter;
 use svg::node::element::Group;
+use svg::node::element::LinearGradient;
 use svg::node::element::Path as SvgPath;
+use svg::node::element::RadialGradient;
+use svg::node::element::Stop;
 use svg::node::element::path::Data;
 
+use geometry::{FilledOutline, GradientSpan, Outline, Segment};
+
 const USER_BASE_SUFFIX: &str = ".local/lib/xhGen";
 const USER_CSV_DIR_SUFFIX: &str = "csv-library";
 const USER_OUTPUT_DIR_SUFFIX: &str = "xhGenerated";
@@ -25,557 +47,1515 @@ const SYSTEM_CSV_DIR: &str = "/usr/local/lib/xhGen/csv-library";
 const DEFAULT_CSV_FILENAME: &str = "unique_crosshair_color_pairs.csv";
 const MIN_CANVAS_SIZE: u32 = 64;
 const MAX_CANVAS_SIZE: u32 = 8192;
+const DEFAULT_SIZE_SET: &str = "16, 24, 32, 48, 64";
+// Images packed into .ico and .cur exports; Windows picks the closest per DPI.
+const ICO_SIZES: [u32; 7] = [16, 24, 32, 48, 64, 128, 256];
+const CUR_SIZES: [u32; 5] = [32, 48, 64, 96, 128];
+const DEFAULT_CURSOR_SIZES: &str = "24, 32, 48, 64";
+// Core X cursor names a crosshair cursor answers to inside a theme.
+const XCURSOR_NAMES: [&str; 3] = ["crosshair", "cross", "tcross"];
 const MAX_RING_OUTER_RADIUS: f64 = 4192.0;
+// Tip widths at or below this meant "razor" in profiles saved before spoke styles.
+const LEGACY_RAZOR_TIP_WIDTH: f64 = 0.01;
 
 #[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 struct CrosshairConfig {
+    // Output canvas in pixels.
     size: u32,
-    ring_outer_radius: f64,
-    ring_thickness: f64,
-    rim_color: (u8, u8, u8, f32), // RGBA with opacity
+    // What every other length below is measured in.
+    units: GeometryUnits,
+    // Canvas width in design units; only read when `units` is `Design`.
+    design_size: f64,
+    rings: Vec<RingConfig>,
+    // Index into `rings` whose inner edge spokes start from (minus `gap_from_ring`).
+    spoke_anchor_ring: usize,
     arm_color: (u8, u8, u8, f32), // RGBA with opacity
+    // Linear fade along every spoke from base to tip; None paints solid arms.
+    arm_gradient: Option<GradientFill>,
     gap_from_ring: f64,
     center_gap_radius: f64,
     spoke_base_width: f64,
     spoke_tip_width: f64,
-    angles: Vec<f64>,
+    spoke_style: SpokeStyle,
+    // Older profiles store a bare `angles` array; each entry becomes a spoke with no overrides.
+    #[serde(alias = "angles", deserialize_with = "deserialize_spokes")]
+    spokes: Vec<SpokeConfig>,
+    symmetry: SpokeSymmetry,
+    center_marker: CenterMarker,
+    contrast_outline: ContrastOutline,
     blur_radius: f32,
     glow_radius: f32,
+    animation: Animation,
 }
 
 impl Default for CrosshairConfig {
     fn default() -> Self {
         Self {
             size: 256,
-            ring_outer_radius: 118.0,
-            ring_thickness: 20.0,
-            rim_color: (255, 255, 255, 1.0),
+            units: GeometryUnits::Design,
+            design_size: 256.0,
+            rings: vec![RingConfig::default()],
+            spoke_anchor_ring: 0,
             arm_color: (0, 0, 0, 1.0),
+            arm_gradient: None,
             gap_from_ring: 10.0,
             center_gap_radius: 2.0,
             spoke_base_width: 12.0,
             spoke_tip_width: 1.5,
-            angles: vec![45.0, 135.0, 225.0, 315.0],
+            spoke_style: SpokeStyle::default(),
+            spokes: [45.0, 135.0, 225.0, 315.0]
+                .into_iter()
+                .map(SpokeConfig::at)
+                .collect(),
+            symmetry: SpokeSymmetry::default(),
+            center_marker: CenterMarker::default(),
+            contrast_outline: ContrastOutline::default(),
             blur_radius: 1.0,
             glow_radius: 2.0,
+            animation: Animation::default(),
         }
     }
 }
 
-fn canvas_border_radius(size: u32) -> f64 {
-    size as f64 / 2.0
-}
-
-fn ring_draw_radius(config: &CrosshairConfig) -> f64 {
-    (config.ring_outer_radius - config.ring_thickness / 2.0).max(0.0)
-}
+impl CrosshairConfig {
+    // Width of the canvas in the units the geometry is stored in.
+    fn design_extent(&self) -> f64 {
+        match self.units {
+            GeometryUnits::Pixels => self.size as f64,
+            GeometryUnits::Design => self.design_size.max(1.0),
+        }
+    }
 
-fn ring_inner_radius(config: &CrosshairConfig) -> f64 {
-    (config.ring_outer_radius - config.ring_thickness).max(0.0)
+    // Switches unit systems without changing how the reticle looks at the current size.
+    fn set_units(&mut self, units: GeometryUnits) {
+        if units == self.units {
+            return;
+        }
+        match units {
+            GeometryUnits::Design => self.design_size = self.size as f64,
+            GeometryUnits::Pixels => scale_lengths(self, self.size as f64 / self.design_extent()),
+        }
+        self.units = units;
+    }
 }
 
-fn spoke_base_radius(config: &CrosshairConfig) -> f64 {
-    (ring_inner_radius(config) - config.gap_from_ring).max(0.0)
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum GeometryUnits {
+    // Lengths are canvas pixels; resizing the canvas leaves shapes at their pixel size.
+    Pixels,
+    // Lengths are measured on a `design_size` canvas and scale with `size`.
+    #[default]
+    Design,
 }
 
-fn spoke_tip_radius(config: &CrosshairConfig) -> f64 {
-    config.center_gap_radius.max(0.0)
+impl GeometryUnits {
+    fn label(self) -> &'static str {
+        match self {
+            GeometryUnits::Pixels => "Pixels",
+            GeometryUnits::Design => "Design units",
+        }
+    }
 }
 
-// ------------------------------------------------------------
-// SVG GENERATION
-// ------------------------------------------------------------
+// Multiplies every stored length (not angles, fractions or opacities) by `factor`.
+fn scale_lengths(config: &mut CrosshairConfig, factor: f64) {
+    let scale = |v: &mut f64| *v *= factor;
+    let scale_style = |style: &mut SpokeStyle| match style {
+        SpokeStyle::Rectangle { corner_radius } => *corner_radius *= factor,
+        SpokeStyle::Chevron { thickness } => *thickness *= factor,
+        SpokeStyle::Tapered { .. }
+        | SpokeStyle::Razor { .. }
+        | SpokeStyle::Triangle { .. }
+        | SpokeStyle::RoundedCap { .. } => {}
+    };
 
-fn bezier_spoke(
-    cx: f64,
-    cy: f64,
-    angle_deg: f64,
-    tip_r: f64,
-    base_r: f64,
-    base_width: f64,
-    tip_width: f64,
-) -> SvgPath {
-    let th = angle_deg.to_radians();
-    let ux = th.cos();
-    let uy = th.sin();
-
-    let px = -uy;
-    let py = ux;
-
-    let (tip_x, tip_y) = (cx + tip_r * ux, cy + tip_r * uy);
-    let (base_x, base_y) = (cx + base_r * ux, cy + base_r * uy);
-
-    let base_half = base_width / 2.0;
-    let tip_half = tip_width / 2.0;
-
-    let (bl_x, bl_y) = (base_x - px * base_half, base_y - py * base_half);
-    let (br_x, br_y) = (base_x + px * base_half, base_y + py * base_half);
-
-    let data = if tip_width <= 0.01 {
-        // Razor tip with gradual taper: wide shoulder -> slimmer mid -> narrow pinch -> point.
-        let dist = (base_r - tip_r).abs();
-
-        let shoulder_r = base_r - dist * 0.25;
-        let mid_r = base_r - dist * 0.6;
-        let pinch_r = base_r - dist * 0.9;
-
-        let shoulder_half = base_half * 0.9;
-        let mid_half = base_half * 0.6;
-        let pinch_half = base_half * 0.18;
-
-        let (shoulder_x, shoulder_y) = (cx + shoulder_r * ux, cy + shoulder_r * uy);
-        let (mid_x, mid_y) = (cx + mid_r * ux, cy + mid_r * uy);
-        let (pinch_x, pinch_y) = (cx + pinch_r * ux, cy + pinch_r * uy);
-
-        let (sr_x, sr_y) = (
-            shoulder_x + px * shoulder_half,
-            shoulder_y + py * shoulder_half,
-        );
-        let (sl_x, sl_y) = (
-            shoulder_x - px * shoulder_half,
-            shoulder_y - py * shoulder_half,
-        );
+    for ring in &mut config.rings {
+        scale(&mut ring.outer_radius);
+        scale(&mut ring.thickness);
+    }
+    scale(&mut config.gap_from_ring);
+    scale(&mut config.center_gap_radius);
+    scale(&mut config.spoke_base_width);
+    scale(&mut config.spoke_tip_width);
+    scale_style(&mut config.spoke_style);
+    for spoke in &mut config.spokes {
+        for value in [
+            &mut spoke.base_width,
+            &mut spoke.tip_width,
+            &mut spoke.gap_from_ring,
+            &mut spoke.tip_radius,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            scale(value);
+        }
+        if let Some(style) = spoke.style.as_mut() {
+            scale_style(style);
+        }
+    }
+    scale(&mut config.center_marker.size);
+    scale(&mut config.center_marker.thickness);
+    scale(&mut config.contrast_outline.width);
+    config.blur_radius *= factor as f32;
+    config.glow_radius *= factor as f32;
+}
 
-        let (mr_x, mr_y) = (mid_x + px * mid_half, mid_y + py * mid_half);
-        let (ml_x, ml_y) = (mid_x - px * mid_half, mid_y - py * mid_half);
-
-        let (pr_x, pr_y) = (pinch_x + px * pinch_half, pinch_y + py * pinch_half);
-        let (pl_x, pl_y) = (pinch_x - px * pinch_half, pinch_y - py * pinch_half);
-
-        Data::new()
-            .move_to((bl_x, bl_y))
-            .line_to((br_x, br_y))
-            .line_to((sr_x, sr_y))
-            .quadratic_curve_to(((sr_x + mr_x) / 2.0, (sr_y + mr_y) / 2.0, mr_x, mr_y))
-            .quadratic_curve_to(((mr_x + pr_x) / 2.0, (mr_y + pr_y) / 2.0, pr_x, pr_y))
-            .quadratic_curve_to(((pr_x + tip_x) / 2.0, (pr_y + tip_y) / 2.0, tip_x, tip_y))
-            .quadratic_curve_to(((pl_x + tip_x) / 2.0, (pl_y + tip_y) / 2.0, pl_x, pl_y))
-            .quadratic_curve_to(((ml_x + pl_x) / 2.0, (ml_y + pl_y) / 2.0, ml_x, ml_y))
-            .quadratic_curve_to(((sl_x + ml_x) / 2.0, (sl_y + ml_y) / 2.0, sl_x, sl_y))
-            .line_to((bl_x, bl_y))
-            .close()
-    } else {
-        let (tl_x, tl_y) = (tip_x - px * tip_half, tip_y - py * tip_half);
-        let (tr_x, tr_y) = (tip_x + px * tip_half, tip_y + py * tip_half);
-
-        // Slightly curve the sides toward the tip to keep the beveled look while adding width at the center.
-        let ctrl_br_x = (br_x + tr_x) / 2.0;
-        let ctrl_br_y = (br_y + tr_y) / 2.0;
-        let ctrl_bl_x = (bl_x + tl_x) / 2.0;
-        let ctrl_bl_y = (bl_y + tl_y) / 2.0;
-
-        Data::new()
-            .move_to((bl_x, bl_y))
-            .line_to((br_x, br_y))
-            .quadratic_curve_to((ctrl_br_x, ctrl_br_y, tr_x, tr_y))
-            .line_to((tl_x, tl_y))
-            .quadratic_curve_to((ctrl_bl_x, ctrl_bl_y, bl_x, bl_y))
-            .close()
-    };
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct RingConfig {
+    outer_radius: f64,
+    thickness: f64,
+    color: (u8, u8, u8, f32), // RGBA with opacity
+    // Multiplies the color alpha; survives batch runs that replace the rim color.
+    opacity: f32,
+    style: RingStyle,
+    // Radial fade from the inner to the outer edge; None paints a solid ring.
+    gradient: Option<GradientFill>,
+}
 
-    SvgPath::new().set("d", data)
+// Gradient toward an end color. The start is the shape's own color, so batch runs
+// that recolor arms or rims keep the same fade.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct GradientFill {
+    // None keeps the start color's RGB and only fades the alpha.
+    end_rgb: Option<(u8, u8, u8)>,
+    end_alpha: f32,
 }
 
-fn generate_svg(config: &CrosshairConfig) -> Document {
-    let cx = config.size as f64 / 2.0;
-    let cy = cx;
+impl Default for GradientFill {
+    fn default() -> Self {
+        Self {
+            end_rgb: None,
+            end_alpha: 0.0,
+        }
+    }
+}
 
-    let base_r = spoke_base_radius(config);
-    let tip_r = spoke_tip_radius(config);
+impl GradientFill {
+    fn end_color(&self, start: (u8, u8, u8, f32)) -> (u8, u8, u8, f32) {
+        let (r, g, b) = self.end_rgb.unwrap_or((start.0, start.1, start.2));
+        (r, g, b, clamp_alpha(self.end_alpha))
+    }
+}
 
-    let rim_color = format!(
-        "rgba({},{},{},{})",
-        config.rim_color.0, config.rim_color.1, config.rim_color.2, config.rim_color.3
-    );
+// How a ring is split into arcs. Angles are in degrees, measured like spoke angles.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum RingStyle {
+    #[default]
+    Full,
+    // `count` arcs spread over start..end with `gap` degrees between neighbours.
+    Segmented {
+        start_angle: f64,
+        end_angle: f64,
+        count: u32,
+        gap: f64,
+    },
+    // Full circle with a `gap`-degree opening centered on every spoke angle.
+    SpokeGaps {
+        gap: f64,
+    },
+}
 
-    let arm_color = format!(
-        "rgba({},{},{},{})",
-        config.arm_color.0, config.arm_color.1, config.arm_color.2, config.arm_color.3
-    );
+impl RingStyle {
+    fn label(&self) -> &'static str {
+        match self {
+            RingStyle::Full => "Full",
+            RingStyle::Segmented { .. } => "Segmented",
+            RingStyle::SpokeGaps { .. } => "Gaps at spokes",
+        }
+    }
 
-    let mut arms = Group::new();
+    fn segmented_default() -> Self {
+        RingStyle::Segmented {
+            start_angle: 0.0,
+            end_angle: 360.0,
+            count: 4,
+            gap: 20.0,
+        }
+    }
 
-    for angle in &config.angles {
-        let path = bezier_spoke(
-            cx,
-            cy,
-            *angle,
-            tip_r,
-            base_r,
-            config.spoke_base_width,
-            config.spoke_tip_width,
-        )
-        .set("fill", arm_color.as_str());
+    fn spoke_gaps_default() -> Self {
+        RingStyle::SpokeGaps { gap: 20.0 }
+    }
+}
 
-        arms = arms.add(path);
+impl Default for RingConfig {
+    fn default() -> Self {
+        Self {
+            outer_radius: 118.0,
+            thickness: 20.0,
+            color: (255, 255, 255, 1.0),
+            opacity: 1.0,
+            style: RingStyle::Full,
+            gradient: None,
+        }
     }
+}
 
-    let ring = Circle::new()
-        .set("cx", cx)
-        .set("cy", cy)
-        .set("r", ring_draw_radius(config))
-        .set("stroke-width", config.ring_thickness)
-        .set("stroke", rim_color.as_str())
-        .set("fill", "none");
+impl RingConfig {
+    fn inner_radius(&self) -> f64 {
+        (self.outer_radius - self.thickness).max(0.0)
+    }
 
-    Document::new()
-        .set("width", config.size)
-        .set("height", config.size)
-        .set("viewBox", format!("0 0 {} {}", config.size, config.size))
-        .add(arms)
-        .add(ring)
+    fn paint(&self) -> (u8, u8, u8, f32) {
+        let (r, g, b, a) = self.color;
+        (r, g, b, clamp_alpha(a * self.opacity))
+    }
 }
 
-// ------------------------------------------------------------
-// COLOR HELPERS
-// ------------------------------------------------------------
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum MarkerShape {
+    #[default]
+    None,
+    Dot,
+    Circle,
+    Plus,
+    Chevron,
+}
 
-fn clamp_alpha(alpha: f32) -> f32 {
-    alpha.clamp(0.0, 1.0)
+impl MarkerShape {
+    const ALL: [MarkerShape; 5] = [
+        MarkerShape::None,
+        MarkerShape::Dot,
+        MarkerShape::Circle,
+        MarkerShape::Plus,
+        MarkerShape::Chevron,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            MarkerShape::None => "None",
+            MarkerShape::Dot => "Dot",
+            MarkerShape::Circle => "Hollow circle",
+            MarkerShape::Plus => "Plus",
+            MarkerShape::Chevron => "Chevron",
+        }
+    }
 }
 
-fn open_path_in_file_manager(path: &Path) -> Result<(), String> {
-    let cmd = if cfg!(target_os = "macos") {
-        "open"
-    } else if cfg!(target_os = "windows") {
-        "explorer"
-    } else {
-        "xdg-open"
-    };
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct CenterMarker {
+    shape: MarkerShape,
+    size: f64,
+    thickness: f64,
+    color: (u8, u8, u8, f32), // RGBA with opacity
+    opacity: f32,
+}
 
-    let status = Command::new(cmd)
-        .arg(path)
-        .status()
-        .map_err(|e| format!("Launch failed: {}", e))?;
+impl Default for CenterMarker {
+    fn default() -> Self {
+        Self {
+            shape: MarkerShape::None,
+            size: 3.0,
+            thickness: 1.5,
+            color: (255, 0, 0, 1.0),
+            opacity: 1.0,
+        }
+    }
+}
 
-    if status.success() {
-        Ok(())
-    } else {
-        Err(format!("File manager exited with status {}", status))
+impl CenterMarker {
+    fn paint(&self) -> (u8, u8, u8, f32) {
+        let (r, g, b, a) = self.color;
+        (r, g, b, clamp_alpha(a * self.opacity))
     }
 }
 
-fn tuple_to_rgba(color: (u8, u8, u8, f32)) -> Rgba {
-    Rgba::from_rgba_unmultiplied(
-        color.0 as f32 / 255.0,
-        color.1 as f32 / 255.0,
-        color.2 as f32 / 255.0,
-        clamp_alpha(color.3),
-    )
+// Stroke drawn outside every spoke and both ring edges so the reticle reads on
+// backgrounds that match its fill.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct ContrastOutline {
+    enabled: bool,
+    width: f64,
+    color: (u8, u8, u8, f32), // RGBA with opacity
 }
 
-fn rgba_to_tuple(rgba: Rgba) -> (u8, u8, u8, f32) {
-    let [r, g, b, a] = rgba.to_rgba_unmultiplied();
-    (
-        (r * 255.0).round().clamp(0.0, 255.0) as u8,
-        (g * 255.0).round().clamp(0.0, 255.0) as u8,
-        (b * 255.0).round().clamp(0.0, 255.0) as u8,
-        clamp_alpha(a),
-    )
+impl Default for ContrastOutline {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            width: 1.0,
+            color: (0, 0, 0, 0.8),
+        }
+    }
 }
 
-fn tuple_to_color32(color: (u8, u8, u8, f32)) -> Color32 {
-    let alpha = (color.3 * 255.0).round().clamp(0.0, 255.0) as u8;
-    Color32::from_rgba_unmultiplied(color.0, color.1, color.2, alpha)
+impl ContrastOutline {
+    fn is_visible(&self) -> bool {
+        self.enabled && self.width > 0.0 && self.color.3 > 0.0
+    }
 }
 
-fn app_icon() -> IconData {
-    icon_data::from_png_bytes(include_bytes!("../resources/icon/icon_full.png"))
-        .expect("bundled icon must load")
+// Spoke silhouette. Widths come from the spoke's base/tip width; variants carry shape extras.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum SpokeStyle {
+    // Base to tip with the sides bowed outward by `bulge` x base width.
+    Tapered { bulge: f64 },
+    // Narrows through fixed stations to a point; `pinch` is the half width left
+    // just before the point, as a fraction of the base half width.
+    Razor { pinch: f64 },
+    // Constant base-width bar; `corner_radius` rounds all four corners.
+    Rectangle { corner_radius: f64 },
+    // Straight sides meeting at the tip, or at the ring end when `inverted`.
+    Triangle { inverted: bool },
+    // Arrowhead pointing at the center with arms `thickness` wide.
+    Chevron { thickness: f64 },
+    // Base-width line with a semicircular tip; `round_base` also rounds the ring end.
+    RoundedCap { round_base: bool },
 }
 
-// ------------------------------------------------------------
-// USER PATH HELPERS & SEEDING
-// ------------------------------------------------------------
+impl Default for SpokeStyle {
+    fn default() -> Self {
+        SpokeStyle::Tapered { bulge: 0.0 }
+    }
+}
 
-fn home_dir() -> Option<PathBuf> {
-    if let Ok(home) = env::var("HOME") {
-        return Some(PathBuf::from(home));
+impl SpokeStyle {
+    const ALL: [SpokeStyle; 6] = [
+        SpokeStyle::Tapered { bulge: 0.0 },
+        SpokeStyle::Razor { pinch: 0.18 },
+        SpokeStyle::Rectangle { corner_radius: 0.0 },
+        SpokeStyle::Triangle { inverted: false },
+        SpokeStyle::Chevron { thickness: 4.0 },
+        SpokeStyle::RoundedCap { round_base: true },
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            SpokeStyle::Tapered { .. } => "Tapered bezier",
+            SpokeStyle::Razor { .. } => "Razor",
+            SpokeStyle::Rectangle { .. } => "Rectangle",
+            SpokeStyle::Triangle { .. } => "Triangle",
+            SpokeStyle::Chevron { .. } => "Chevron",
+            SpokeStyle::RoundedCap { .. } => "Rounded cap",
+        }
     }
-    if let Ok(profile) = env::var("USERPROFILE") {
-        return Some(PathBuf::from(profile));
+
+    fn same_kind(&self, other: &SpokeStyle) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
     }
-    None
 }
 
-fn user_base_dir() -> PathBuf {
-    home_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join(USER_BASE_SUFFIX)
+// Keyframed changes to numeric config fields, played over `duration` seconds.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct Animation {
+    enabled: bool,
+    duration: f64,
+    fps: u32,
+    // Number of plays for exported files; 0 loops forever.
+    loops: u32,
+    tracks: Vec<AnimationTrack>,
 }
 
-fn user_csv_dir() -> PathBuf {
-    user_base_dir().join(USER_CSV_DIR_SUFFIX)
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration: 1.0,
+            fps: 24,
+            loops: 0,
+            tracks: Vec::new(),
+        }
+    }
 }
 
-fn default_csv_path() -> PathBuf {
-    user_csv_dir().join(DEFAULT_CSV_FILENAME)
+impl Animation {
+    fn is_active(&self) -> bool {
+        self.enabled && !self.tracks.is_empty() && self.duration > 0.0
+    }
 }
 
-fn user_output_dir() -> PathBuf {
-    user_base_dir().join(USER_OUTPUT_DIR_SUFFIX)
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct AnimationTrack {
+    // Serialized field path such as `rings[0].outer_radius`; `spokes[*].angle`
+    // targets every spoke.
+    field: String,
+    // Keyframe values are added to the configured value instead of replacing it.
+    relative: bool,
+    keyframes: Vec<Keyframe>,
 }
 
-fn profiles_dir() -> PathBuf {
-    user_base_dir().join(USER_PROFILE_DIR_SUFFIX)
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Keyframe {
+    // Seconds from the start of the cycle.
+    time: f64,
+    value: f64,
+    // Curve from this keyframe to the next one.
+    easing: Easing,
 }
 
-fn ensure_profiles_dir() -> io::Result<PathBuf> {
-    let dir = profiles_dir();
-    fs::create_dir_all(&dir)?;
-    Ok(dir)
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Easing {
+    #[default]
+    Linear,
+    EaseInOut,
+    // Keeps this keyframe's value until the next one.
+    Hold,
 }
 
-fn seed_user_csvs() -> Result<(), String> {
-    let user_dir = user_csv_dir();
-    fs::create_dir_all(&user_dir).map_err(|e| format!("Create dir failed: {}", e))?;
+impl Easing {
+    const ALL: [Easing; 3] = [Easing::Linear, Easing::EaseInOut, Easing::Hold];
 
-    let mut seeded = false;
+    fn label(self) -> &'static str {
+        match self {
+            Easing::Linear => "Linear",
+            Easing::EaseInOut => "Ease in/out",
+            Easing::Hold => "Hold",
+        }
+    }
+}
 
-    // Copy from system install if present.
-    let system_dir = Path::new(SYSTEM_CSV_DIR);
-    if system_dir.exists() {
-        for entry in fs::read_dir(system_dir).map_err(|e| format!("Read dir failed: {}", e))? {
-            let entry = entry.map_err(|e| format!("Dir entry error: {}", e))?;
-            if entry.path().extension().and_then(|e| e.to_str()) != Some("csv") {
-                continue;
-            }
-            let dest = user_dir.join(entry.file_name());
-            if !dest.exists() {
-                fs::copy(entry.path(), &dest).map_err(|e| format!("Copy failed: {}", e))?;
-                seeded = true;
-            }
+impl AnimationTrack {
+    fn keyed(field: &str, relative: bool, keys: &[(f64, f64, Easing)]) -> Self {
+        Self {
+            field: field.to_string(),
+            relative,
+            keyframes: keys
+                .iter()
+                .map(|&(time, value, easing)| Keyframe {
+                    time,
+                    value,
+                    easing,
+                })
+                .collect(),
         }
     }
+}
 
-    // Copy from repo resources as a fallback.
-    let repo_dir = Path::new("resources/csv-defaults");
-    if repo_dir.exists() {
-        for entry in fs::read_dir(repo_dir).map_err(|e| format!("Read dir failed: {}", e))? {
-            let entry = entry.map_err(|e| format!("Dir entry error: {}", e))?;
-            if entry.path().extension().and_then(|e| e.to_str()) != Some("csv") {
-                continue;
-            }
-            let dest = user_dir.join(entry.file_name());
-            if !dest.exists() {
-                fs::copy(entry.path(), &dest).map_err(|e| format!("Copy failed: {}", e))?;
-                seeded = true;
-            }
+// Starting points for the common effects, keyed to the current cycle length.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AnimationPreset {
+    PulseRing,
+    RotateSpokes,
+    FireSpread,
+}
+
+impl AnimationPreset {
+    const ALL: [AnimationPreset; 3] = [
+        AnimationPreset::PulseRing,
+        AnimationPreset::RotateSpokes,
+        AnimationPreset::FireSpread,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            AnimationPreset::PulseRing => "Pulse ring",
+            AnimationPreset::RotateSpokes => "Rotate spokes",
+            AnimationPreset::FireSpread => "Spread on fire",
         }
     }
 
-    if seeded || default_csv_path().exists() {
-        Ok(())
-    } else {
-        Err("Could not locate default CSV files to seed the user directory.".to_string())
+    fn track(self, config: &CrosshairConfig) -> AnimationTrack {
+        let d = config.animation.duration.max(0.0);
+        match self {
+            AnimationPreset::PulseRing => AnimationTrack::keyed(
+                "rings[*].opacity",
+                false,
+                &[
+                    (0.0, 1.0, Easing::EaseInOut),
+                    (d / 2.0, 0.35, Easing::EaseInOut),
+                    (d, 1.0, Easing::Linear),
+                ],
+            ),
+            AnimationPreset::RotateSpokes => AnimationTrack::keyed(
+                "spokes[*].angle",
+                true,
+                &[(0.0, 0.0, Easing::Linear), (d, 360.0, Easing::Linear)],
+            ),
+            AnimationPreset::FireSpread => {
+                // Kick out fast, settle back over the rest of the cycle.
+                let kick = config.spoke_base_width.max(1.0) * 1.5;
+                AnimationTrack::keyed(
+                    "center_gap_radius",
+                    true,
+                    &[
+                        (0.0, 0.0, Easing::Linear),
+                        (d * 0.1, kick, Easing::EaseInOut),
+                        (d, 0.0, Easing::Linear),
+                    ],
+                )
+            }
+        }
     }
 }
 
-fn default_csv_path_string() -> String {
-    default_csv_path().to_string_lossy().to_string()
+// Per-spoke settings. `None` fields fall back to the matching global value on the config.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct SpokeConfig {
+    angle: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tip_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gap_from_ring: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tip_radius: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<(u8, u8, u8, f32)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<SpokeStyle>,
 }
 
-fn default_output_dir_string() -> String {
-    user_output_dir().to_string_lossy().to_string()
+impl SpokeConfig {
+    fn at(angle: f64) -> Self {
+        Self {
+            angle,
+            ..Self::default()
+        }
+    }
+
+    fn base_width(&self, config: &CrosshairConfig) -> f64 {
+        self.base_width.unwrap_or(config.spoke_base_width)
+    }
+
+    fn tip_width(&self, config: &CrosshairConfig) -> f64 {
+        self.tip_width.unwrap_or(config.spoke_tip_width)
+    }
+
+    fn gap_from_ring(&self, config: &CrosshairConfig) -> f64 {
+        self.gap_from_ring.unwrap_or(config.gap_from_ring)
+    }
+
+    fn tip_radius(&self, config: &CrosshairConfig) -> f64 {
+        self.tip_radius.unwrap_or(config.center_gap_radius)
+    }
+
+    fn color(&self, config: &CrosshairConfig) -> (u8, u8, u8, f32) {
+        self.color.unwrap_or(config.arm_color)
+    }
+
+    fn style(&self, config: &CrosshairConfig) -> SpokeStyle {
+        self.style.unwrap_or(config.spoke_style)
+    }
+
+    fn has_overrides(&self) -> bool {
+        self.base_width.is_some()
+            || self.tip_width.is_some()
+            || self.gap_from_ring.is_some()
+            || self.tip_radius.is_some()
+            || self.color.is_some()
+            || self.style.is_some()
+    }
 }
 
-fn sanitize_profile_name(raw: &str) -> Option<String> {
-    let trimmed = raw.trim();
-    if trimmed.is_empty() {
-        return None;
+fn deserialize_spokes<'de, D>(deserializer: D) -> Result<Vec<SpokeConfig>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum SpokeEntry {
+        Angle(f64),
+        Spoke(SpokeConfig),
     }
-    let cleaned: String = trimmed
-        .chars()
-        .map(|c| {
-            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
-                c
-            } else {
-                '_'
-            }
+
+    let entries = Vec::<SpokeEntry>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| match entry {
+            SpokeEntry::Angle(angle) => SpokeConfig::at(angle),
+            SpokeEntry::Spoke(spoke) => spoke,
         })
-        .collect();
-    if cleaned.is_empty() {
-        None
-    } else {
-        Some(cleaned)
-    }
+        .collect())
 }
 
-fn profile_path(name: &str) -> Result<PathBuf, String> {
-    let safe = sanitize_profile_name(name).ok_or("Enter a profile name.")?;
-    let dir = ensure_profiles_dir().map_err(|e| format!("Profile dir error: {}", e))?;
-    Ok(dir.join(format!("{}.json", safe)))
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SymmetryMode {
+    #[default]
+    Off,
+    // `count` evenly spaced spokes starting at `offset`.
+    Rotational,
+    // Editing a spoke also rewrites its reflection across `mirror_axis`.
+    Mirror,
 }
 
-fn list_profiles() -> Result<Vec<String>, String> {
-    let dir = ensure_profiles_dir().map_err(|e| format!("Profile dir error: {}", e))?;
-    let mut names = Vec::new();
-    for entry in fs::read_dir(&dir).map_err(|e| format!("Read dir failed: {}", e))? {
-        let entry = entry.map_err(|e| format!("Dir entry error: {}", e))?;
-        let path = entry.path();
-        if path.is_file() {
-            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                names.push(stem.to_string());
-            }
+impl SymmetryMode {
+    const ALL: [SymmetryMode; 3] = [
+        SymmetryMode::Off,
+        SymmetryMode::Rotational,
+        SymmetryMode::Mirror,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SymmetryMode::Off => "Off",
+            SymmetryMode::Rotational => "Rotational",
+            SymmetryMode::Mirror => "Mirror",
         }
     }
-    names.sort();
-    names.dedup();
-    Ok(names)
 }
 
-fn save_profile_to_disk(config: &CrosshairConfig, name: &str) -> Result<PathBuf, String> {
-    let path = profile_path(name)?;
-    let file = fs::File::create(&path).map_err(|e| format!("Save failed: {}", e))?;
-    serde_json::to_writer_pretty(file, config).map_err(|e| format!("Write failed: {}", e))?;
-    Ok(path)
+// Editing aids for spoke angles. Spokes are always stored as explicit angles, so
+// renderers and batch runs never look at these settings.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct SpokeSymmetry {
+    mode: SymmetryMode,
+    count: u32,
+    offset: f64,
+    // Degrees, measured like spoke angles; 90 mirrors left/right.
+    mirror_axis: f64,
+    // Angle drags round to multiples of this; 0 disables snapping.
+    snap_increment: f64,
 }
 
-fn load_profile_from_disk(name: &str) -> Result<CrosshairConfig, String> {
-    let path = profile_path(name)?;
-    let data = fs::read_to_string(&path).map_err(|e| format!("Read failed: {}", e))?;
-    serde_json::from_str(&data).map_err(|e| format!("Parse failed: {}", e))
+impl Default for SpokeSymmetry {
+    fn default() -> Self {
+        Self {
+            mode: SymmetryMode::Off,
+            count: 4,
+            offset: 45.0,
+            mirror_axis: 90.0,
+            snap_increment: 0.0,
+        }
+    }
+}
+
+// Tolerance in degrees when matching a spoke to its mirror partner.
+const MIRROR_MATCH_EPSILON: f64 = 0.01;
+
+fn normalize_angle(angle: f64) -> f64 {
+    let wrapped = angle.rem_euclid(360.0);
+    if wrapped >= 360.0 { 0.0 } else { wrapped }
+}
+
+fn snap_angle(angle: f64, increment: f64) -> f64 {
+    if increment <= 0.0 {
+        return angle;
+    }
+    (angle / increment).round() * increment
+}
+
+fn mirror_angle(axis: f64, angle: f64) -> f64 {
+    normalize_angle(2.0 * axis - angle)
+}
+
+fn same_angle(a: f64, b: f64) -> bool {
+    let diff = normalize_angle(a - b);
+    diff.min(360.0 - diff) <= MIRROR_MATCH_EPSILON
+}
+
+// Regenerates the angles for rotational symmetry. Existing spokes keep their
+// overrides by position; extra spokes inherit everything.
+fn apply_rotational_symmetry(config: &mut CrosshairConfig) {
+    let count = config.symmetry.count.max(1) as usize;
+    let step = 360.0 / count as f64;
+    config.spokes.truncate(count);
+    while config.spokes.len() < count {
+        config.spokes.push(SpokeConfig::default());
+    }
+    for (i, spoke) in config.spokes.iter_mut().enumerate() {
+        spoke.angle = normalize_angle(config.symmetry.offset + step * i as f64);
+    }
+}
+
+// Copies an edited spoke onto the spoke that mirrored it before the edit.
+fn apply_mirror_edit(config: &mut CrosshairConfig, before: &[SpokeConfig], edited: usize) {
+    let axis = config.symmetry.mirror_axis;
+    let Some(old) = before.get(edited) else {
+        return;
+    };
+    let partner_angle = mirror_angle(axis, old.angle);
+    if same_angle(partner_angle, old.angle) {
+        return;
+    }
+    let Some(partner) =
+        (0..before.len()).find(|&i| i != edited && same_angle(before[i].angle, partner_angle))
+    else {
+        return;
+    };
+
+    let source = config.spokes[edited].clone();
+    config.spokes[partner] = SpokeConfig {
+        angle: mirror_angle(axis, source.angle),
+        ..source
+    };
+}
+
+fn canvas_border_radius(config: &CrosshairConfig) -> f64 {
+    config.design_extent() / 2.0
+}
+
+// A batch row's rim color applies to every ring; per-ring opacity is kept.
+fn set_rim_color(config: &mut CrosshairConfig, color: (u8, u8, u8, f32)) {
+    for ring in &mut config.rings {
+        ring.color = color;
+    }
 }
 
 // ------------------------------------------------------------
-// PREVIEW GEOMETRY
+// SVG GENERATION
 // ------------------------------------------------------------
 
-fn add_quad_samples(points: &mut Vec<(f64, f64)>, ctrl: (f64, f64), end: (f64, f64), steps: usize) {
-    let start = match points.last().copied() {
-        Some(p) => p,
-        None => return,
+fn outline_to_data(outline: &Outline) -> Data {
+    outline
+        .segments
+        .iter()
+        .fold(Data::new(), |data, segment| match *segment {
+            Segment::MoveTo(p) => data.move_to(p),
+            Segment::LineTo(p) => data.line_to(p),
+            Segment::QuadTo(ctrl, end) => data.quadratic_curve_to((ctrl.0, ctrl.1, end.0, end.1)),
+            Segment::Close => data.close(),
+        })
+}
+
+// Element the svg crate has no typed builder for (filter primitives, SMIL animations).
+fn raw_element(name: &str, attrs: &[(&str, String)]) -> Element {
+    let mut element = Element::new(name);
+    for (key, value) in attrs {
+        element.assign(*key, value.as_str());
+    }
+    element
+}
+
+// Soft edges plus an outer halo: the source is blurred by `blur`, and a copy of its
+// alpha is dilated and blurred by `glow`, tinted with `color`, and merged underneath.
+fn effect_filter(
+    id: &str,
+    size: f64,
+    blur: f32,
+    glow: f32,
+    color: (u8, u8, u8, f32),
+) -> Option<Filter> {
+    let blur = blur.max(0.0);
+    let glow = glow.max(0.0);
+    if blur <= 0.0 && glow <= 0.0 {
+        return None;
+    }
+
+    let mut filter = Filter::new()
+        .set("id", id)
+        .set("filterUnits", "userSpaceOnUse")
+        .set("x", 0)
+        .set("y", 0)
+        .set("width", size)
+        .set("height", size);
+
+    let source = if blur > 0.0 {
+        filter = filter.add(raw_element(
+            "feGaussianBlur",
+            &[
+                ("in", "SourceGraphic".to_string()),
+                ("stdDeviation", blur.to_string()),
+                ("result", "soft".to_string()),
+            ],
+        ));
+        "soft"
+    } else {
+        "SourceGraphic"
     };
 
-    for i in 1..=steps {
-        let t = i as f64 / steps as f64;
-        let omt = 1.0 - t;
-        let x = omt * omt * start.0 + 2.0 * omt * t * ctrl.0 + t * t * end.0;
-        let y = omt * omt * start.1 + 2.0 * omt * t * ctrl.1 + t * t * end.1;
-        points.push((x, y));
-    }
-}
-
-fn spoke_outline_points(
-    cx: f64,
-    cy: f64,
-    angle_deg: f64,
-    tip_r: f64,
-    base_r: f64,
-    base_width: f64,
-    tip_width: f64,
-) -> Vec<(f64, f64)> {
-    let th = angle_deg.to_radians();
-    let ux = th.cos();
-    let uy = th.sin();
-
-    let px = -uy;
-    let py = ux;
-
-    let tip_x = cx + tip_r * ux;
-    let tip_y = cy + tip_r * uy;
-    let base_x = cx + base_r * ux;
-    let base_y = cy + base_r * uy;
-
-    let base_half = base_width / 2.0;
-    let tip_half = tip_width / 2.0;
-
-    let bl_x = base_x - px * base_half;
-    let bl_y = base_y - py * base_half;
-    let br_x = base_x + px * base_half;
-    let br_y = base_y + py * base_half;
-
-    if tip_width <= 0.01 {
-        let dist = (base_r - tip_r).abs();
-        let shoulder_r = base_r - dist * 0.25;
-        let mid_r = base_r - dist * 0.6;
-        let pinch_r = base_r - dist * 0.9;
-
-        let shoulder_half = base_half * 0.9;
-        let mid_half = base_half * 0.6;
-        let pinch_half = base_half * 0.18;
-
-        let shoulder_x = cx + shoulder_r * ux;
-        let shoulder_y = cy + shoulder_r * uy;
-        let mid_x = cx + mid_r * ux;
-        let mid_y = cy + mid_r * uy;
-        let pinch_x = cx + pinch_r * ux;
-        let pinch_y = cy + pinch_r * uy;
-
-        let sr_x = shoulder_x + px * shoulder_half;
-        let sr_y = shoulder_y + py * shoulder_half;
-        let sl_x = shoulder_x - px * shoulder_half;
-        let sl_y = shoulder_y - py * shoulder_half;
-
-        let mr_x = mid_x + px * mid_half;
-        let mr_y = mid_y + py * mid_half;
-        let ml_x = mid_x - px * mid_half;
-        let ml_y = mid_y - py * mid_half;
-
-        let pr_x = pinch_x + px * pinch_half;
-        let pr_y = pinch_y + py * pinch_half;
-        let pl_x = pinch_x - px * pinch_half;
-        let pl_y = pinch_y - py * pinch_half;
-
-        let mut pts = vec![(bl_x, bl_y), (br_x, br_y), (sr_x, sr_y)];
-        add_quad_samples(
-            &mut pts,
-            ((sr_x + mr_x) / 2.0, (sr_y + mr_y) / 2.0),
-            (mr_x, mr_y),
-            6,
-        );
-        add_quad_samples(
-            &mut pts,
-            ((mr_x + pr_x) / 2.0, (mr_y + pr_y) / 2.0),
-            (pr_x, pr_y),
-            6,
-        );
-        add_quad_samples(
-            &mut pts,
-            ((pr_x + tip_x) / 2.0, (pr_y + tip_y) / 2.0),
-            (tip_x, tip_y),
-            6,
+    if glow <= 0.0 {
+        return Some(filter);
+    }
+
+    filter = filter
+        .add(raw_element(
+            "feMorphology",
+            &[
+                ("in", "SourceAlpha".to_string()),
+                ("operator", "dilate".to_string()),
+                ("radius", glow.to_string()),
+                ("result", "spread".to_string()),
+            ],
+        ))
+        .add(raw_element(
+            "feGaussianBlur",
+            &[
+                ("in", "spread".to_string()),
+                ("stdDeviation", glow.to_string()),
+                ("result", "halo".to_string()),
+            ],
+        ))
+        .add(raw_element(
+            "feFlood",
+            &[
+                (
+                    "flood-color",
+                    format!("rgb({},{},{})", color.0, color.1, color.2),
+                ),
+                ("flood-opacity", clamp_alpha(color.3).to_string()),
+                ("result", "tint".to_string()),
+            ],
+        ))
+        .add(raw_element(
+            "feComposite",
+            &[
+                ("in", "tint".to_string()),
+                ("in2", "halo".to_string()),
+                ("operator", "in".to_string()),
+                ("result", "glow".to_string()),
+            ],
+        ));
+
+    let mut merge = Element::new("feMerge");
+    merge.append(raw_element("feMergeNode", &[("in", "glow".to_string())]));
+    merge.append(raw_element("feMergeNode", &[("in", source.to_string())]));
+
+    Some(filter.add(merge))
+}
+
+fn gradient_stop(offset: f64, color: (u8, u8, u8, f32)) -> Stop {
+    Stop::new()
+        .set("offset", offset)
+        .set(
+            "stop-color",
+            format!("rgb({},{},{})", color.0, color.1, color.2),
+        )
+        .set("stop-opacity", clamp_alpha(color.3))
+}
+
+// Builds the `<defs>` gradient for a shape and the `fill` value referencing it.
+fn gradient_paint(id: &str, shape: &FilledOutline) -> Option<(Element, String)> {
+    let element: Element = match shape.gradient? {
+        GradientSpan::Linear {
+            from,
+            to,
+            end_color,
+        } => LinearGradient::new()
+            .set("id", id)
+            .set("gradientUnits", "userSpaceOnUse")
+            .set("x1", from.0)
+            .set("y1", from.1)
+            .set("x2", to.0)
+            .set("y2", to.1)
+            .add(gradient_stop(0.0, shape.color))
+            .add(gradient_stop(1.0, end_color))
+            .into(),
+        GradientSpan::Radial {
+            center,
+            inner_r,
+            outer_r,
+            end_color,
+        } => {
+            let start = if outer_r > 0.0 {
+                inner_r / outer_r
+            } else {
+                0.0
+            };
+            RadialGradient::new()
+                .set("id", id)
+                .set("gradientUnits", "userSpaceOnUse")
+                .set("cx", center.0)
+                .set("cy", center.1)
+                .set("r", outer_r)
+                .add(gradient_stop(start, shape.color))
+                .add(gradient_stop(1.0, end_color))
+                .into()
+        }
+    };
+    Some((element, format!("url(#{})", id)))
+}
+
+// The stroke is painted under the fill, so only the outer `width` of a doubled stroke shows.
+fn with_contrast_outline(group: Group, outline: &ContrastOutline) -> Group {
+    if !outline.is_visible() {
+        return group;
+    }
+    group
+        .set("stroke", css_rgba(outline.color))
+        .set("stroke-width", outline.width * 2.0)
+        .set("stroke-linejoin", "round")
+        .set("paint-order", "stroke")
+}
+
+// Shared defs and drawable layers (arms, rings, marker) of one reticle. `prefix`
+// namespaces gradient and filter ids so several reticles can share a document.
+struct SvgLayers {
+    defs: Option<Definitions>,
+    layers: Vec<Element>,
+}
+
+fn reticle_layers(config: &CrosshairConfig, prefix: &str) -> SvgLayers {
+    let geometry = geometry::build_geometry(config);
+
+    let mut arms = Group::new();
+
+    let mut defs = Definitions::new();
+    let mut has_defs = false;
+
+    // The glow is tinted with the spoke color: one filter per distinct color, set on
+    // the whole group when every spoke shares it.
+    let mut glow_colors: Vec<(u8, u8, u8, f32)> = Vec::new();
+    for spoke in &geometry.spokes {
+        if !glow_colors.contains(&spoke.color) {
+            glow_colors.push(spoke.color);
+        }
+    }
+    if glow_colors.is_empty() {
+        glow_colors.push(config.arm_color);
+    }
+    let mut arm_filters = Vec::new();
+    for (idx, &color) in glow_colors.iter().enumerate() {
+        let id = if glow_colors.len() == 1 {
+            format!("{}-arms-fx", prefix)
+        } else {
+            format!("{}-arms-fx-{}", prefix, idx)
+        };
+        if let Some(filter) = effect_filter(
+            &id,
+            config.design_extent(),
+            config.blur_radius,
+            config.glow_radius,
+            color,
+        ) {
+            defs = defs.add(filter);
+            has_defs = true;
+            arm_filters.push(format!("url(#{})", id));
+        }
+    }
+    let per_spoke_filters = arm_filters.len() > 1;
+
+    for (idx, spoke) in geometry.spokes.iter().enumerate() {
+        let fill = match gradient_paint(&format!("{}-spoke-grad-{}", prefix, idx), spoke) {
+            Some((gradient, fill)) => {
+                defs = defs.add(gradient);
+                has_defs = true;
+                fill
+            }
+            None => css_rgba(spoke.color),
+        };
+
+        let mut path = SvgPath::new()
+            .set("d", outline_to_data(&spoke.outline))
+            .set("fill", fill);
+        if per_spoke_filters
+            && let Some(color_idx) = glow_colors.iter().position(|c| *c == spoke.color)
+        {
+            path = path.set("filter", arm_filters[color_idx].clone());
+        }
+
+        arms = arms.add(path);
+    }
+
+    arms = with_contrast_outline(arms, &config.contrast_outline);
+    if !per_spoke_filters && let Some(filter) = arm_filters.first() {
+        arms = arms.set("filter", filter.clone());
+    }
+
+    let mut rings = Group::new();
+    for (idx, shape) in geometry.rings.iter().enumerate() {
+        let fill = match gradient_paint(&format!("{}-ring-grad-{}", prefix, idx), shape) {
+            Some((gradient, fill)) => {
+                defs = defs.add(gradient);
+                has_defs = true;
+                fill
+            }
+            None => css_rgba(shape.color),
+        };
+
+        let mut ring = Group::new().add(
+            SvgPath::new()
+                .set("d", outline_to_data(&shape.outline))
+                .set("fill", fill)
+                .set("fill-rule", "evenodd"),
         );
-        add_quad_samples(
-            &mut pts,
-            ((pl_x + tip_x) / 2.0, (pl_y + tip_y) / 2.0),
-            (pl_x, pl_y),
-            6,
+
+        let id = format!("{}-ring-fx-{}", prefix, idx);
+        if let Some(filter) = effect_filter(
+            &id,
+            config.design_extent(),
+            config.blur_radius,
+            config.glow_radius,
+            shape.color,
+        ) {
+            defs = defs.add(filter);
+            ring = ring.set("filter", format!("url(#{})", id));
+            has_defs = true;
+        }
+
+        rings = rings.add(ring);
+    }
+    rings = with_contrast_outline(rings, &config.contrast_outline);
+
+    let mut layers: Vec<Element> = vec![arms.into(), rings.into()];
+    if let Some(marker) = &geometry.marker {
+        layers.push(
+            SvgPath::new()
+                .set("d", outline_to_data(&marker.outline))
+                .set("fill", css_rgba(marker.color))
+                .set("fill-rule", "evenodd")
+                .into(),
         );
-        add_quad_samples(
-            &mut pts,
-            ((ml_x + pl_x) / 2.0, (ml_y + pl_y) / 2.0),
-            (ml_x, ml_y),
-            6,
+    }
+
+    SvgLayers {
+        defs: has_defs.then_some(defs),
+        layers,
+    }
+}
+
+// Document shell shared by static and animated output. The viewBox is in design
+// units, so the same geometry renders at any pixel size.
+fn svg_canvas(config: &CrosshairConfig) -> Document {
+    let extent = config.design_extent();
+    Document::new()
+        .set("width", config.size)
+        .set("height", config.size)
+        .set("viewBox", format!("0 0 {} {}", extent, extent))
+}
+
+fn svg_document(config: &CrosshairConfig) -> Document {
+    svg_canvas(config).add(metadata::config_element(config))
+}
+
+fn generate_svg(config: &CrosshairConfig) -> Document {
+    add_reticle(svg_document(config), config)
+}
+
+// The reticle without its embedded config, for previews inlined into other files.
+fn generate_bare_svg(config: &CrosshairConfig) -> Document {
+    add_reticle(svg_canvas(config), config)
+}
+
+fn add_reticle(mut document: Document, config: &CrosshairConfig) -> Document {
+    let SvgLayers { defs, layers } = reticle_layers(config, "xh");
+    if let Some(defs) = defs {
+        document = document.add(defs);
+    }
+    for layer in layers {
+        document = document.add(layer);
+    }
+    document
+}
+
+// SMIL flipbook: every frame is a complete reticle that is visible only during its
+// time slot. Unlike interpolating path data this works for any animated field,
+// including ones that change the number of path segments. The cost is size: the
+// file holds one full copy of the reticle per distinct frame, so runs of identical
+// frames (holds, finished tracks) share a single copy.
+fn generate_animated_svg(config: &CrosshairConfig) -> Result<Document, String> {
+    let times = animation::frame_times(config);
+    let count = times.len();
+    let cycle = count as f64 / config.animation.fps.max(1) as f64;
+    let repeat = match config.animation.loops {
+        0 => "indefinite".to_string(),
+        n => n.to_string(),
+    };
+
+    // (first frame, frame count, layers) for each run of identical frames.
+    let mut runs: Vec<(usize, usize, SvgLayers)> = Vec::new();
+    let mut previous: Option<String> = None;
+    for (idx, &time) in times.iter().enumerate() {
+        let frame_config = animation::config_at(config, time)?;
+        let key = serde_json::to_string(&frame_config).map_err(|e| e.to_string())?;
+        match runs.last_mut() {
+            Some(run) if previous.as_ref() == Some(&key) => run.1 += 1,
+            _ => {
+                let id_prefix = format!("xh-f{}", runs.len());
+                runs.push((idx, 1, reticle_layers(&frame_config, &id_prefix)));
+            }
+        }
+        previous = Some(key);
+    }
+
+    let mut document = svg_document(config);
+    for (start_frame, frames, SvgLayers { defs, layers }) in runs {
+        // The first run stays visible in viewers without SMIL support.
+        let first = start_frame == 0;
+        let mut frame = Group::new().set("visibility", if first { "visible" } else { "hidden" });
+        if let Some(defs) = defs {
+            frame = frame.add(defs);
+        }
+        for layer in layers {
+            frame = frame.add(layer);
+        }
+
+        let last = start_frame + frames == count;
+        if !(first && last) {
+            let start = start_frame as f64 / count as f64;
+            let end = (start_frame + frames) as f64 / count as f64;
+            let (values, key_times) = if first {
+                ("visible;hidden", format!("0;{}", end))
+            } else if last {
+                ("hidden;visible", format!("0;{}", start))
+            } else {
+                ("hidden;visible;hidden", format!("0;{};{}", start, end))
+            };
+            frame = frame.add(raw_element(
+                "animate",
+                &[
+                    ("attributeName", "visibility".to_string()),
+                    ("values", values.to_string()),
+                    ("keyTimes", key_times),
+                    ("calcMode", "discrete".to_string()),
+                    ("dur", format!("{}s", cycle)),
+                    ("repeatCount", repeat.clone()),
+                    ("fill", "freeze".to_string()),
+                ],
+            ));
+        }
+        document = document.add(frame);
+    }
+    Ok(document)
+}
+
+// ------------------------------------------------------------
+// COLOR HELPERS
+// ------------------------------------------------------------
+
+fn clamp_alpha(alpha: f32) -> f32 {
+    alpha.clamp(0.0, 1.0)
+}
+
+fn css_rgba(color: (u8, u8, u8, f32)) -> String {
+    format!("rgba({},{},{},{})", color.0, color.1, color.2, color.3)
+}
+
+fn open_path_in_file_manager(path: &Path) -> Result<(), String> {
+    let cmd = if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(target_os = "windows") {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+
+    let status = Command::new(cmd)
+        .arg(path)
+        .status()
+        .map_err(|e| format!("Launch failed: {}", e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("File manager exited with status {}", status))
+    }
+}
+
+fn tuple_to_rgba(color: (u8, u8, u8, f32)) -> Rgba {
+    Rgba::from_rgba_unmultiplied(
+        color.0 as f32 / 255.0,
+        color.1 as f32 / 255.0,
+        color.2 as f32 / 255.0,
+        clamp_alpha(color.3),
+    )
+}
+
+fn rgba_to_tuple(rgba: Rgba) -> (u8, u8, u8, f32) {
+    let [r, g, b, a] = rgba.to_rgba_unmultiplied();
+    (
+        (r * 255.0).round().clamp(0.0, 255.0) as u8,
+        (g * 255.0).round().clamp(0.0, 255.0) as u8,
+        (b * 255.0).round().clamp(0.0, 255.0) as u8,
+        clamp_alpha(a),
+    )
+}
+
+fn tuple_to_color32(color: (u8, u8, u8, f32)) -> Color32 {
+    let alpha = (color.3 * 255.0).round().clamp(0.0, 255.0) as u8;
+    Color32::from_rgba_unmultiplied(color.0, color.1, color.2, alpha)
+}
+
+fn app_icon() -> IconData {
+    icon_data::from_png_bytes(include_bytes!("../resources/icon/icon_full.png"))
+        .expect("bundled icon must load")
+}
+
+// ------------------------------------------------------------
+// USER PATH HELPERS & SEEDING
+// ------------------------------------------------------------
+
+fn home_dir() -> Option<PathBuf> {
+    if let Ok(home) = env::var("HOME") {
+        return Some(PathBuf::from(home));
+    }
+    if let Ok(profile) = env::var("USERPROFILE") {
+        return Some(PathBuf::from(profile));
+    }
+    None
+}
+
+fn user_base_dir() -> PathBuf {
+    home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(USER_BASE_SUFFIX)
+}
+
+fn user_csv_dir() -> PathBuf {
+    user_base_dir().join(USER_CSV_DIR_SUFFIX)
+}
+
+fn default_csv_path() -> PathBuf {
+    user_csv_dir().join(DEFAULT_CSV_FILENAME)
+}
+
+fn user_output_dir() -> PathBuf {
+    user_base_dir().join(USER_OUTPUT_DIR_SUFFIX)
+}
+
+fn profiles_dir() -> PathBuf {
+    user_base_dir().join(USER_PROFILE_DIR_SUFFIX)
+}
+
+fn ensure_profiles_dir() -> io::Result<PathBuf> {
+    let dir = profiles_dir();
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn seed_user_csvs() -> Result<(), String> {
+    let user_dir = user_csv_dir();
+    fs::create_dir_all(&user_dir).map_err(|e| format!("Create dir failed: {}", e))?;
+
+    let mut seeded = false;
+
+    // Copy from system install if present.
+    let system_dir = Path::new(SYSTEM_CSV_DIR);
+    if system_dir.exists() {
+        for entry in fs::read_dir(system_dir).map_err(|e| format!("Read dir failed: {}", e))? {
+            let entry = entry.map_err(|e| format!("Dir entry error: {}", e))?;
+            if entry.path().extension().and_then(|e| e.to_str()) != Some("csv") {
+                continue;
+            }
+            let dest = user_dir.join(entry.file_name());
+            if !dest.exists() {
+                fs::copy(entry.path(), &dest).map_err(|e| format!("Copy failed: {}", e))?;
+                seeded = true;
+            }
+        }
+    }
+
+    // Copy from repo resources as a fallback.
+    let repo_dir = Path::new("resources/csv-defaults");
+    if repo_dir.exists() {
+        for entry in fs::read_dir(repo_dir).map_err(|e| format!("Read dir failed: {}", e))? {
+            let entry = entry.map_err(|e| format!("Dir entry error: {}", e))?;
+            if entry.path().extension().and_then(|e| e.to_str()) != Some("csv") {
+                continue;
+            }
+            let dest = user_dir.join(entry.file_name());
+            if !dest.exists() {
+                fs::copy(entry.path(), &dest).map_err(|e| format!("Copy failed: {}", e))?;
+                seeded = true;
+            }
+        }
+    }
+
+    if seeded || default_csv_path().exists() {
+        Ok(())
+    } else {
+        Err("Could not locate default CSV files to seed the user directory.".to_string())
+    }
+}
+
+fn default_csv_path_string() -> String {
+    default_csv_path().to_string_lossy().to_string()
+}
+
+fn default_output_dir_string() -> String {
+    user_output_dir().to_string_lossy().to_string()
+}
+
+fn sanitize_profile_name(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let cleaned: String = trimmed
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if cleaned.is_empty() {
+        None
+    } else {
+        Some(cleaned)
+    }
+}
+
+fn profile_path(name: &str) -> Result<PathBuf, String> {
+    let safe = sanitize_profile_name(name).ok_or("Enter a profile name.")?;
+    let dir = ensure_profiles_dir().map_err(|e| format!("Profile dir error: {}", e))?;
+    Ok(dir.join(format!("{}.json", safe)))
+}
+
+fn list_profiles() -> Result<Vec<String>, String> {
+    let dir = ensure_profiles_dir().map_err(|e| format!("Profile dir error: {}", e))?;
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&dir).map_err(|e| format!("Read dir failed: {}", e))? {
+        let entry = entry.map_err(|e| format!("Dir entry error: {}", e))?;
+        let path = entry.path();
+        if path.is_file()
+            && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+        {
+            names.push(stem.to_string());
+        }
+    }
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+fn save_profile_to_disk(config: &CrosshairConfig, name: &str) -> Result<PathBuf, String> {
+    let path = profile_path(name)?;
+    let file = fs::File::create(&path).map_err(|e| format!("Save failed: {}", e))?;
+    serde_json::to_writer_pretty(file, config).map_err(|e| format!("Write failed: {}", e))?;
+    Ok(path)
+}
+
+fn load_profile_from_disk(name: &str) -> Result<CrosshairConfig, String> {
+    let path = profile_path(name)?;
+    let data = fs::read_to_string(&path).map_err(|e| format!("Read failed: {}", e))?;
+    config_from_json(&data)
+}
+
+fn config_from_json(data: &str) -> Result<CrosshairConfig, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(data).map_err(|e| format!("Parse failed: {}", e))?;
+    config_from_value(value)
+}
+
+fn config_from_value(mut value: serde_json::Value) -> Result<CrosshairConfig, String> {
+    migrate_profile(&mut value);
+    serde_json::from_value(value).map_err(|e| format!("Parse failed: {}", e))
+}
+
+// Upgrades older profile layouts in place before deserializing.
+fn migrate_profile(value: &mut serde_json::Value) {
+    let Some(obj) = value.as_object_mut() else {
+        return;
+    };
+
+    // Single-ring profiles stored the ring as top-level fields.
+    if !obj.contains_key("rings") && obj.contains_key("ring_outer_radius") {
+        let mut ring = serde_json::Map::new();
+        for (old, new) in [
+            ("ring_outer_radius", "outer_radius"),
+            ("ring_thickness", "thickness"),
+            ("rim_color", "color"),
+        ] {
+            if let Some(v) = obj.remove(old) {
+                ring.insert(new.to_string(), v);
+            }
+        }
+        obj.insert(
+            "rings".to_string(),
+            serde_json::Value::Array(vec![serde_json::Value::Object(ring)]),
         );
-        add_quad_samples(
-            &mut pts,
-            ((sl_x + ml_x) / 2.0, (sl_y + ml_y) / 2.0),
-            (sl_x, sl_y),
-            6,
+    }
+
+    // Profiles without units were drawn in canvas pixels; their size becomes the design
+    // size so they render identically at that size and scale proportionally elsewhere.
+    if !obj.contains_key("units") {
+        let size = obj
+            .get("size")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(CrosshairConfig::default().size as f64);
+        obj.insert(
+            "units".to_string(),
+            serde_json::to_value(GeometryUnits::Design).unwrap_or_default(),
         );
-        pts.push((bl_x, bl_y));
-        return pts;
+        obj.insert("design_size".to_string(), serde_json::Value::from(size));
     }
 
-    let tl_x = tip_x - px * tip_half;
-    let tl_y = tip_y - py * tip_half;
-    let tr_x = tip_x + px * tip_half;
-    let tr_y = tip_y + py * tip_half;
+    // Before spoke styles existed, a near-zero tip width implied the razor shape.
+    if !obj.contains_key("spoke_style") {
+        let is_razor = |tip: Option<&serde_json::Value>, fallback: f64| {
+            tip.and_then(|v| v.as_f64()).unwrap_or(fallback) <= LEGACY_RAZOR_TIP_WIDTH
+        };
+        let style_value = |razor: bool| {
+            let style = if razor {
+                SpokeStyle::Razor { pinch: 0.18 }
+            } else {
+                SpokeStyle::default()
+            };
+            serde_json::to_value(style).unwrap_or_default()
+        };
+
+        let default_tip = CrosshairConfig::default().spoke_tip_width;
+        let global_razor = is_razor(obj.get("spoke_tip_width"), default_tip);
+        obj.insert("spoke_style".to_string(), style_value(global_razor));
+
+        if let Some(spokes) = obj.get_mut("spokes").and_then(|v| v.as_array_mut()) {
+            for spoke in spokes.iter_mut().filter_map(|v| v.as_object_mut()) {
+                let Some(tip) = spoke.get("tip_width") else {
+                    continue;
+                };
+                let razor = is_razor(Some(tip), default_tip);
+                if razor != global_razor {
+                    spoke.insert("style".to_string(), style_value(razor));
+                }
+            }
+        }
+    }
+}
 
-    let ctrl_br_x = (br_x + tr_x) / 2.0;
-    let ctrl_br_y = (br_y + tr_y) / 2.0;
-    let ctrl_bl_x = (bl_x + tl_x) / 2.0;
-    let ctrl_bl_y = (bl_y + tl_y) / 2.0;
+// ------------------------------------------------------------
+// PREVIEW
+// ------------------------------------------------------------
 
-    let mut pts = vec![(bl_x, bl_y), (br_x, br_y)];
-    add_quad_samples(&mut pts, (ctrl_br_x, ctrl_br_y), (tr_x, tr_y), 6);
-    pts.push((tl_x, tl_y));
-    add_quad_samples(&mut pts, (ctrl_bl_x, ctrl_bl_y), (bl_x, bl_y), 6);
-    pts
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PreviewMode {
+    Vector,
+    Raster,
 }
 
-fn draw_crosshair_preview(ui: &mut egui::Ui, config: &CrosshairConfig) {
+const OVERLAY_VIEWPORT_ID: &str = "crosshair-overlay";
+
+// Center of the control window in points. egui reports monitor sizes but not where
+// monitors sit, so this point is what picks the overlay's monitor. None where the
+// platform hides window positions (e.g. Wayland).
+fn window_center(ctx: &egui::Context) -> Option<Pos2> {
+    ctx.input(|i| i.viewport().outer_rect)
+        .map(|rect| rect.center())
+}
+
+// Samples per quadratic segment when flattening outlines for egui.
+const PREVIEW_CURVE_STEPS: usize = 6;
+
+// Diff mode marks pixels where any channel of the preview mesh and the SVG render
+// differs by more than this; anti-aliased edges stay below it.
+const DIFF_THRESHOLD: u8 = 48;
+const DIFF_HIGHLIGHT: (u8, u8, u8) = (255, 0, 255);
+
+// The mesh never draws blur or glow, so it is compared against an SVG render with
+// both off; otherwise every soft edge would light up and hide real faults.
+fn raster_diff_texture(
+    ctx: &egui::Context,
+    config: &CrosshairConfig,
+) -> Result<egui::TextureHandle, String> {
+    let unfiltered = CrosshairConfig {
+        blur_radius: 0.0,
+        glow_radius: 0.0,
+        ..config.clone()
+    };
+    let svg = raster::rasterize_document(&generate_svg(&unfiltered), config.size)?;
+    let mesh = raster::rasterize_preview_mesh(config)?;
+    let mask = raster::diff_mask(&mesh, &svg, DIFF_THRESHOLD, DIFF_HIGHLIGHT)?;
+    let size = [mask.width() as usize, mask.height() as usize];
+    let image = egui::ColorImage::from_rgba_premultiplied(size, mask.data());
+    Ok(ctx.load_texture("raster-diff", image, egui::TextureOptions::NEAREST))
+}
+
+// `diff` is drawn over the raster: opaque where the vector preview and the SVG disagree.
+fn draw_crosshair_preview(
+    ui: &mut egui::Ui,
+    config: &CrosshairConfig,
+    raster: Option<&egui::TextureHandle>,
+    diff: Option<&egui::TextureHandle>,
+) {
     let available = ui.available_size();
     if available.x <= 0.0 || available.y <= 0.0 {
         return;
@@ -584,64 +1564,87 @@ fn draw_crosshair_preview(ui: &mut egui::Ui, config: &CrosshairConfig) {
     let side = available.x.min(available.y).max(140.0);
     let (rect, _) = ui.allocate_exact_size(vec2(side, side), egui::Sense::hover());
     let painter = ui.painter_at(rect);
-    let center = rect.center();
 
-    let scale = side / config.size as f32;
-    let half = config.size as f32 / 2.0;
+    painter.rect_filled(rect, 8.0, ui.visuals().faint_bg_color);
+
+    match raster {
+        Some(texture) => {
+            let uv = egui::Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+            painter.image(texture.id(), rect, uv, Color32::WHITE);
+            if let Some(diff) = diff {
+                painter.image(diff.id(), rect, uv, Color32::WHITE);
+            }
+        }
+        None => paint_vector_preview(&painter, rect, config),
+    }
+}
 
-    let rim_color = tuple_to_color32(config.rim_color);
-    let arm_color = tuple_to_color32(config.arm_color);
+// Fast egui approximation of the reticle.
+fn paint_vector_preview(painter: &egui::Painter, rect: egui::Rect, config: &CrosshairConfig) {
+    let center = rect.center();
+    let scale = rect.width() / config.design_extent() as f32;
+    let half = config.design_extent() as f32 / 2.0;
+    let to_screen = |(x, y): (f64, f64)| {
+        pos2(
+            center.x + ((x as f32 - half) * scale),
+            center.y + ((y as f32 - half) * scale),
+        )
+    };
 
-    let base_r = spoke_base_radius(config);
-    let tip_r = spoke_tip_radius(config);
+    let geometry = geometry::build_geometry(config);
 
-    painter.rect_filled(rect, 8.0, ui.visuals().faint_bg_color);
-    for angle in &config.angles {
-        let points = spoke_outline_points(
-            config.size as f64 / 2.0,
-            config.size as f64 / 2.0,
-            *angle,
-            tip_r,
-            base_r,
-            config.spoke_base_width,
-            config.spoke_tip_width,
-        );
+    let contrast = config.contrast_outline.is_visible().then(|| Stroke {
+        width: (config.contrast_outline.width * 2.0) as f32 * scale,
+        color: tuple_to_color32(config.contrast_outline.color),
+    });
 
-        if points.len() < 3 {
-            continue;
+    for spoke in &geometry.spokes {
+        if let Some(stroke) = contrast {
+            stroke_outline(painter, &spoke.outline, stroke, &to_screen);
         }
+        fill_outline(painter, spoke, &to_screen);
+    }
+    for ring in &geometry.rings {
+        if let Some(stroke) = contrast {
+            stroke_outline(painter, &ring.outline, stroke, &to_screen);
+        }
+        fill_outline(painter, ring, &to_screen);
+    }
+    if let Some(marker) = &geometry.marker {
+        fill_outline(painter, marker, &to_screen);
+    }
+}
 
-        let screen_points: Vec<Pos2> = points
-            .into_iter()
-            .map(|(x, y)| {
-                pos2(
-                    center.x + ((x as f32 - half) * scale),
-                    center.y + ((y as f32 - half) * scale),
-                )
-            })
-            .collect();
-
-        painter.add(egui::Shape::convex_polygon(
-            screen_points,
-            arm_color,
-            Stroke::NONE,
-        ));
+fn stroke_outline(
+    painter: &egui::Painter,
+    outline: &Outline,
+    stroke: Stroke,
+    to_screen: &dyn Fn((f64, f64)) -> Pos2,
+) {
+    for points in outline.flatten(PREVIEW_CURVE_STEPS) {
+        let screen_points: Vec<Pos2> = points.into_iter().map(to_screen).collect();
+        painter.add(egui::Shape::closed_line(screen_points, stroke));
     }
+}
 
-    painter.circle_stroke(
-        center,
-        (ring_draw_radius(config) as f32 * scale).max(0.5),
-        Stroke {
-            width: (config.ring_thickness as f32 * scale).max(1.0),
-            color: rim_color,
-        },
-    );
+// Gradients are sampled per vertex; egui interpolates across each triangle, which
+// is exact for linear spans and close enough for radial ones at preview sizes.
+fn fill_outline(
+    painter: &egui::Painter,
+    shape: &FilledOutline,
+    to_screen: &dyn Fn((f64, f64)) -> Pos2,
+) {
+    let triangulation = tessellate::tessellate(&shape.outline.flatten(PREVIEW_CURVE_STEPS));
+    if triangulation.indices.is_empty() {
+        return;
+    }
 
-    painter.circle_filled(
-        center,
-        (config.center_gap_radius as f32 * scale).max(0.0),
-        ui.visuals().extreme_bg_color,
-    );
+    let mut mesh = egui::Mesh::default();
+    for point in triangulation.vertices {
+        mesh.colored_vertex(to_screen(point), tuple_to_color32(shape.color_at(point)));
+    }
+    mesh.indices = triangulation.indices;
+    painter.add(mesh);
 }
 
 // ------------------------------------------------------------
@@ -692,13 +1695,21 @@ fn parse_color_spec(raw: &str) -> Result<ColorSpec, io::Error> {
     Ok(ColorSpec { rgb, hex })
 }
 
-fn load_color_pairs(path: &str) -> Result<Vec<(ColorSpec, ColorSpec)>, Box<dyn std::error::Error>> {
+// One CSV row: rim,arm[,outline_hex[,outline_width]]. Empty optional cells keep the config's outline.
+struct BatchRow {
+    rim: ColorSpec,
+    arms: ColorSpec,
+    outline: Option<ColorSpec>,
+    outline_width: Option<f64>,
+}
+
+fn load_color_pairs(path: &str) -> Result<Vec<BatchRow>, Box<dyn std::error::Error>> {
     if Path::new(path) == default_csv_path() {
         let _ = seed_user_csvs();
     }
 
     let csv = fs::read_to_string(path)?;
-    let mut pairs = Vec::new();
+    let mut rows = Vec::new();
 
     for (idx, line) in csv.lines().enumerate() {
         if idx == 0 {
@@ -708,45 +1719,485 @@ fn load_color_pairs(path: &str) -> Result<Vec<(ColorSpec, ColorSpec)>, Box<dyn s
             continue;
         }
 
-        let (outer, inner) = line.split_once(',').ok_or_else(|| {
+        let invalid_row = || {
             io::Error::new(
                 io::ErrorKind::InvalidData,
                 format!("Invalid row {}: {}", idx + 1, line),
             )
-        })?;
+        };
+
+        let cells: Vec<&str> = line.split(',').map(str::trim).collect();
+        if cells.len() < 2 || cells.len() > 4 {
+            return Err(invalid_row().into());
+        }
+
+        let outline = match cells.get(2) {
+            Some(cell) if !cell.is_empty() => Some(parse_color_spec(cell)?),
+            _ => None,
+        };
+        let outline_width = match cells.get(3) {
+            // Range checks are left to the validator so bad widths skip the row.
+            Some(cell) if !cell.is_empty() => {
+                Some(cell.parse::<f64>().ok().ok_or_else(invalid_row)?)
+            }
+            _ => None,
+        };
+
+        rows.push(BatchRow {
+            rim: parse_color_spec(cells[0])?,
+            arms: parse_color_spec(cells[1])?,
+            outline,
+            outline_width,
+        });
+    }
+
+    Ok(rows)
+}
+
+// ------------------------------------------------------------
+// EXPORT
+// ------------------------------------------------------------
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Svg,
+    Png,
+    Both,
+    Ico,
+    Cur,
+}
+
+impl ExportFormat {
+    const ALL: [ExportFormat; 5] = [
+        ExportFormat::Svg,
+        ExportFormat::Png,
+        ExportFormat::Both,
+        ExportFormat::Ico,
+        ExportFormat::Cur,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Svg => "SVG",
+            ExportFormat::Png => "PNG",
+            ExportFormat::Both => "SVG + PNG",
+            ExportFormat::Ico => "ICO",
+            ExportFormat::Cur => "CUR",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "svg" => Some(ExportFormat::Svg),
+            "png" => Some(ExportFormat::Png),
+            "both" => Some(ExportFormat::Both),
+            "ico" => Some(ExportFormat::Ico),
+            "cur" => Some(ExportFormat::Cur),
+            _ => None,
+        }
+    }
+
+    fn writes_svg(self) -> bool {
+        matches!(self, ExportFormat::Svg | ExportFormat::Both)
+    }
+
+    fn writes_png(self) -> bool {
+        matches!(self, ExportFormat::Png | ExportFormat::Both)
+    }
+
+    // Extension, container kind and packed sizes for the Windows formats.
+    fn windows_icon(self) -> Option<(&'static str, winicon::IconKind, &'static [u32])> {
+        match self {
+            ExportFormat::Ico => Some(("ico", winicon::IconKind::Icon, &ICO_SIZES)),
+            ExportFormat::Cur => Some(("cur", winicon::IconKind::Cursor, &CUR_SIZES)),
+            ExportFormat::Svg | ExportFormat::Png | ExportFormat::Both => None,
+        }
+    }
+}
+
+// Writes the reticle next to `base_path`, swapping the extension per format.
+fn write_reticle(
+    config: &CrosshairConfig,
+    base_path: &Path,
+    format: ExportFormat,
+) -> Result<Vec<PathBuf>, String> {
+    let doc = generate_svg(config);
+    let mut written = Vec::new();
+
+    if format.writes_svg() {
+        let path = base_path.with_extension("svg");
+        svg::save(&path, &doc).map_err(|e| format!("Save failed: {}", e))?;
+        written.push(path);
+    }
 
-        pairs.push((parse_color_spec(outer)?, parse_color_spec(inner)?));
+    if format.writes_png() {
+        let path = base_path.with_extension("png");
+        let pixmap = raster::rasterize_document(&doc, config.size)?;
+        raster::save_png(&pixmap, &path)?;
+        written.push(path);
     }
 
-    Ok(pairs)
+    if let Some((extension, kind, sizes)) = format.windows_icon() {
+        let path = base_path.with_extension(extension);
+        let images = sizes
+            .iter()
+            .map(|&size| {
+                let sized = hinting::config_for_size(config, size, true);
+                raster::rasterize_document(&generate_svg(&sized), size)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let bytes = winicon::encode(&images, kind)?;
+        fs::write(&path, bytes).map_err(|e| format!("Save failed: {}", e))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+// Renders the profile once per size as `<stem>_<size>px.<ext>`, optionally hinted
+// to each size's pixel grid.
+fn write_size_set(
+    config: &CrosshairConfig,
+    base_path: &Path,
+    sizes: &[u32],
+    snap: bool,
+    format: ExportFormat,
+) -> Result<Vec<PathBuf>, String> {
+    let stem = base_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "reticle".to_string());
+    if let Some(parent) = base_path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent).map_err(|e| format!("Could not create folder: {}", e))?;
+    }
+
+    let mut written = Vec::new();
+    for &size in sizes {
+        let sized = hinting::config_for_size(config, size, snap);
+        // `parse_size_list` already bounds the sizes; the editor's canvas minimum
+        // does not apply to exported sizes.
+        let issues: Vec<validate::Issue> = validate::validate(&sized)
+            .into_iter()
+            .filter(|issue| issue.field != "size")
+            .collect();
+        if validate::has_errors(&issues) {
+            return Err(format!(
+                "{} px is invalid ({})",
+                size,
+                validate::summarize(&issues)
+            ));
+        }
+        let path = base_path.with_file_name(format!("{}_{}px", stem, size));
+        written.extend(write_reticle(&sized, &path, format)?);
+    }
+    Ok(written)
+}
+
+// Writes `<stem>_cursor`, an Xcursor file holding one hinted image per nominal size
+// with the hotspot at the canvas center. With `theme` set it also writes
+// `<stem>-cursor-theme/` (index.theme plus cursors/) next to it, ready to copy
+// into ~/.icons.
+fn write_xcursor(
+    config: &CrosshairConfig,
+    base_path: &Path,
+    sizes: &[u32],
+    theme: bool,
+) -> Result<Vec<PathBuf>, String> {
+    if let Some(parent) = base_path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent).map_err(|e| format!("Could not create folder: {}", e))?;
+    }
+
+    let pixmaps = sizes
+        .iter()
+        .map(|&size| {
+            let sized = hinting::config_for_size(config, size, true);
+            raster::rasterize_document(&generate_svg(&sized), size)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let images: Vec<xcursor::CursorImage> = sizes
+        .iter()
+        .zip(&pixmaps)
+        .map(|(&size, pixmap)| xcursor::CursorImage {
+            nominal_size: size,
+            pixmap,
+            hotspot: (size / 2, size / 2),
+        })
+        .collect();
+    let bytes = xcursor::encode(&images);
+
+    let stem = base_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "reticle".to_string());
+    let write = |path: PathBuf, contents: &[u8]| {
+        fs::write(&path, contents)
+            .map(|_| path.clone())
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    };
+
+    let mut written = vec![write(
+        base_path.with_file_name(format!("{}_cursor", stem)),
+        &bytes,
+    )?];
+
+    if theme {
+        let theme_dir = base_path.with_file_name(format!("{}-cursor-theme", stem));
+        let cursors_dir = theme_dir.join("cursors");
+        fs::create_dir_all(&cursors_dir)
+            .map_err(|e| format!("Could not create {}: {}", cursors_dir.display(), e))?;
+        let index = format!(
+            "[Icon Theme]\nName={}\nComment=Reticle cursor generated by crosshair-gen\nInherits=default\n",
+            stem
+        );
+        written.push(write(theme_dir.join("index.theme"), index.as_bytes())?);
+        for name in XCURSOR_NAMES {
+            written.push(write(cursors_dir.join(name), &bytes)?);
+        }
+    }
+
+    Ok(written)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AnimationFormat {
+    Svg,
+    Apng,
+    Gif,
+}
+
+impl AnimationFormat {
+    const ALL: [AnimationFormat; 3] = [
+        AnimationFormat::Svg,
+        AnimationFormat::Apng,
+        AnimationFormat::Gif,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            AnimationFormat::Svg => "Animated SVG",
+            AnimationFormat::Apng => "APNG",
+            AnimationFormat::Gif => "GIF",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            AnimationFormat::Svg => "svg",
+            AnimationFormat::Apng => "png",
+            AnimationFormat::Gif => "gif",
+        }
+    }
+}
+
+// Writes one animation cycle next to `base_path` as `<stem>_anim.<ext>`, so it
+// never overwrites the static export.
+fn write_animation(
+    config: &CrosshairConfig,
+    base_path: &Path,
+    format: AnimationFormat,
+) -> Result<PathBuf, String> {
+    let stem = base_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "reticle".to_string());
+    let path = base_path.with_file_name(format!("{}_anim.{}", stem, format.extension()));
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent).map_err(|e| format!("Could not create folder: {}", e))?;
+    }
+
+    let (fps, loops) = (config.animation.fps.max(1), config.animation.loops);
+    match format {
+        AnimationFormat::Svg => svg::save(&path, &generate_animated_svg(config)?)
+            .map_err(|e| format!("Save failed: {}", e))?,
+        AnimationFormat::Apng => {
+            raster::save_apng(&render_animation_frames(config)?, fps, loops, &path)?
+        }
+        AnimationFormat::Gif => {
+            raster::save_gif(&render_animation_frames(config)?, fps, loops, &path)?
+        }
+    }
+    Ok(path)
+}
+
+fn render_animation_frames(
+    config: &CrosshairConfig,
+) -> Result<Vec<resvg::tiny_skia::Pixmap>, String> {
+    animation::frame_times(config)
+        .into_iter()
+        .map(|time| {
+            let frame_config = animation::config_at(config, time)?;
+            raster::rasterize_document(&generate_svg(&frame_config), config.size)
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct BatchReport {
+    rendered: usize,
+    // One message per row that failed validation and was left out.
+    skipped: Vec<String>,
+    // Contact sheet files, when one was requested.
+    contact_sheet: Vec<PathBuf>,
+    // `index.html` listing every rendered reticle, when one was requested.
+    gallery: Option<PathBuf>,
+    // Atlas pages and manifest, in atlas mode.
+    atlas: Vec<PathBuf>,
+}
+
+#[derive(Clone, Copy)]
+struct BatchOptions {
+    format: ExportFormat,
+    // Stop at the first invalid row instead of skipping it.
+    strict: bool,
+    contact_sheet: bool,
+    gallery: bool,
+    // Pack reticles into atlas pages instead of writing one file set per row.
+    atlas: bool,
+    // Print one line per written file.
+    verbose: bool,
 }
 
 fn generate_batch_svgs(
     config: &CrosshairConfig,
     csv_path: &str,
     out_dir: &Path,
-    verbose: bool,
-) -> Result<usize, Box<dyn std::error::Error>> {
+    options: BatchOptions,
+) -> Result<BatchReport, Box<dyn std::error::Error>> {
+    let BatchOptions {
+        format,
+        strict,
+        contact_sheet,
+        gallery: write_gallery,
+        atlas: pack_atlas,
+        verbose,
+    } = options;
+    let issues = validate::validate(config);
+    if validate::has_errors(&issues) {
+        return Err(format!("Config is invalid ({})", validate::summarize(&issues)).into());
+    }
+
     let pairs = load_color_pairs(csv_path)?;
     fs::create_dir_all(out_dir)?;
 
-    let mut cfg = config.clone();
-    for (idx, (rim, arms)) in pairs.iter().enumerate() {
-        cfg.rim_color = (rim.rgb.0, rim.rgb.1, rim.rgb.2, 1.0);
-        cfg.arm_color = (arms.rgb.0, arms.rgb.1, arms.rgb.2, 1.0);
+    let mut report = BatchReport::default();
+    let mut sheet = Vec::new();
+    let mut gallery_entries = Vec::new();
+    let mut sprites = Vec::new();
+    for (idx, row) in pairs.iter().enumerate() {
+        let (rim, arms) = (&row.rim, &row.arms);
+        let mut cfg = config.clone();
+        set_rim_color(&mut cfg, (rim.rgb.0, rim.rgb.1, rim.rgb.2, 1.0));
+        cfg.arm_color = (arms.rgb.0, arms.rgb.1, arms.rgb.2, 1.0);
+
+        let mut stem = format!("xhMan_{}px-rim-{}_arms-{}", config.size, rim.hex, arms.hex);
+        let mut label = vec![format!("rim #{}", rim.hex), format!("arms #{}", arms.hex)];
+        let mut colors = vec![
+            ("rim".to_string(), rim.hex.clone()),
+            ("arms".to_string(), arms.hex.clone()),
+        ];
+        if let Some(outline) = &row.outline {
+            let alpha = cfg.contrast_outline.color.3;
+            cfg.contrast_outline.enabled = true;
+            cfg.contrast_outline.color = (outline.rgb.0, outline.rgb.1, outline.rgb.2, alpha);
+            stem.push_str(&format!("_outline-{}", outline.hex));
+            label.push(format!("outline #{}", outline.hex));
+            colors.push(("outline".to_string(), outline.hex.clone()));
+        }
+        if let Some(width) = row.outline_width {
+            cfg.contrast_outline.width = width;
+        }
+
+        // Rows only recolor the base config, but an outline width can still be invalid.
+        let row_issues = validate::validate(&cfg);
+        if let Some(issue) = row_issues
+            .iter()
+            .find(|issue| issue.severity == validate::Severity::Error)
+        {
+            let message = format!("row {}: {}", idx + 1, issue);
+            if strict {
+                return Err(message.into());
+            }
+            if verbose {
+                println!("{:>3}/{} skipped ({})", idx + 1, pairs.len(), issue);
+            }
+            report.skipped.push(message);
+            continue;
+        }
+
+        let written = if pack_atlas {
+            Vec::new()
+        } else {
+            write_reticle(&cfg, &out_dir.join(&stem), format)?
+        };
+
+        if verbose {
+            for path in &written {
+                println!("{:>3}/{} -> {}", idx + 1, pairs.len(), path.display());
+            }
+        }
+        report.rendered += 1;
+        if write_gallery {
+            let files: Vec<String> = written
+                .iter()
+                .filter_map(|path| path.file_name())
+                .map(|name| name.to_string_lossy().to_string())
+                .collect();
+            // Atlas and ICO/CUR rows leave no image a browser can show.
+            let inline_svg = (!files.iter().any(|file| gallery::is_web_image(file)))
+                .then(|| generate_bare_svg(&cfg).to_string());
+            gallery_entries.push(gallery::GalleryEntry {
+                name: stem.clone(),
+                files,
+                colors: colors.clone(),
+                contrast: gallery::contrast_ratio(rim.rgb, arms.rgb),
+                inline_svg,
+            });
+        }
+        if pack_atlas {
+            sprites.push(atlas::AtlasSprite {
+                name: stem,
+                colors,
+                config: cfg.clone(),
+            });
+        }
+        if contact_sheet {
+            sheet.push(contact_sheet::SheetEntry { label, config: cfg });
+        }
+    }
 
-        let filename = format!("xhMan_256px-rim-{}_arms-{}.svg", rim.hex, arms.hex);
-        let path = out_dir.join(&filename);
+    if !sprites.is_empty() {
+        report.atlas = atlas::write_atlas(&sprites, out_dir)?;
+        if verbose {
+            for path in &report.atlas {
+                println!("atlas -> {}", path.display());
+            }
+        }
+    }
 
-        let doc = generate_svg(&cfg);
-        svg::save(&path, &doc)?;
+    if write_gallery {
+        let gallery_path = gallery::write_gallery(&gallery_entries, out_dir)?;
+        if verbose {
+            println!("gallery -> {}", gallery_path.display());
+        }
+        report.gallery = Some(gallery_path);
+    }
 
+    if !sheet.is_empty() {
+        report.contact_sheet = contact_sheet::write_contact_sheet(&sheet, out_dir)?;
         if verbose {
-            println!("{:>3}/{} -> {}", idx + 1, pairs.len(), path.display());
+            for path in &report.contact_sheet {
+                println!("contact sheet -> {}", path.display());
+            }
         }
     }
 
-    Ok(pairs.len())
+    Ok(report)
 }
 
 // ------------------------------------------------------------
@@ -763,6 +2214,39 @@ struct CrosshairApp {
     available_profiles: Vec<String>,
     status: Option<String>,
     chain_canvas_and_radius: bool,
+    export_format: ExportFormat,
+    preview_mode: PreviewMode,
+    diff_preview: bool,
+    raster_preview: Option<RasterPreview>,
+    raster_error: Option<String>,
+    // Comma-separated pixel sizes for the size-set export.
+    size_set: String,
+    size_set_snap: bool,
+    cursor_sizes: String,
+    cursor_theme: bool,
+    animation_playing: bool,
+    // `ctx.input(|i| i.time)` when playback started.
+    animation_started: f64,
+    // Share code typed in for import.
+    cs2_import: String,
+    batch_contact_sheet: bool,
+    batch_gallery: bool,
+    batch_atlas: bool,
+    overlay_enabled: bool,
+    // Overlay size relative to the canvas size, in physical pixels.
+    overlay_scale: f32,
+    // Physical pixels from the monitor center.
+    overlay_offset: [f32; 2],
+    // A point on the monitor the overlay covers, in points; None lets the system pick.
+    overlay_anchor: Option<Pos2>,
+}
+
+// Rasterized `generate_svg` output, keyed by the SVG source it was built from.
+struct RasterPreview {
+    source: String,
+    texture: egui::TextureHandle,
+    // Pixels where the preview mesh and the SVG disagree; built when diff mode is on.
+    diff: Option<egui::TextureHandle>,
 }
 
 impl CrosshairApp {
@@ -783,40 +2267,237 @@ impl CrosshairApp {
             available_profiles: profiles,
             status,
             chain_canvas_and_radius: false,
+            export_format: ExportFormat::Svg,
+            preview_mode: PreviewMode::Vector,
+            diff_preview: false,
+            raster_preview: None,
+            raster_error: None,
+            size_set: DEFAULT_SIZE_SET.to_string(),
+            size_set_snap: true,
+            cursor_sizes: DEFAULT_CURSOR_SIZES.to_string(),
+            cursor_theme: false,
+            animation_playing: false,
+            animation_started: 0.0,
+            cs2_import: String::new(),
+            batch_contact_sheet: false,
+            batch_gallery: false,
+            batch_atlas: false,
+            overlay_enabled: false,
+            overlay_scale: 1.0,
+            overlay_offset: [0.0, 0.0],
+            overlay_anchor: None,
         }
     }
 
-    fn save_current_svg(&mut self) {
+    // The config as drawn right now: the animated state while playback runs. A track
+    // that cannot be applied leaves the preview static; the issue list says why.
+    fn preview_config(&self, ctx: &egui::Context) -> CrosshairConfig {
+        let animation = &self.config.animation;
+        if !(self.animation_playing && animation.is_active()) {
+            return self.config.clone();
+        }
+        ctx.request_repaint();
+        let elapsed = ctx.input(|i| i.time) - self.animation_started;
+        animation::config_at(&self.config, elapsed.rem_euclid(animation.duration))
+            .unwrap_or_else(|_| self.config.clone())
+    }
+
+    fn refresh_raster_preview(&mut self, ctx: &egui::Context, config: &CrosshairConfig) {
+        let doc = generate_svg(config);
+        let source = doc.to_string();
+        if let Some(preview) = self
+            .raster_preview
+            .as_mut()
+            .filter(|preview| preview.source == source)
+        {
+            if self.diff_preview && preview.diff.is_none() {
+                match raster_diff_texture(ctx, config) {
+                    Ok(texture) => preview.diff = Some(texture),
+                    Err(err) => self.raster_error = Some(err),
+                }
+            }
+            return;
+        }
+
+        let max_side = ctx.input(|i| i.max_texture_side);
+        if config.size as usize > max_side {
+            self.raster_preview = None;
+            self.raster_error = Some(format!(
+                "Canvas {} px exceeds the GPU texture limit of {} px.",
+                config.size, max_side
+            ));
+            return;
+        }
+
+        match raster::rasterize_document(&doc, config.size) {
+            Ok(pixmap) => {
+                let size = [pixmap.width() as usize, pixmap.height() as usize];
+                let image = egui::ColorImage::from_rgba_premultiplied(size, pixmap.data());
+                let texture =
+                    ctx.load_texture("raster-preview", image, egui::TextureOptions::NEAREST);
+                self.raster_preview = Some(RasterPreview {
+                    source,
+                    texture,
+                    diff: None,
+                });
+                self.raster_error = None;
+                // Builds the diff texture for the new source when diff mode is on.
+                self.refresh_raster_preview(ctx, config);
+            }
+            Err(err) => {
+                self.raster_preview = None;
+                self.raster_error = Some(err);
+            }
+        }
+    }
+
+    fn save_animation(&mut self, format: AnimationFormat) {
         let target = PathBuf::from(self.output_path.trim());
         if target.as_os_str().is_empty() {
             self.status = Some("Please enter an output file path.".to_string());
             return;
         }
+        let issues = validate::validate(&self.config);
+        if validate::has_errors(&issues) {
+            self.status = Some(format!(
+                "Fix the config before saving ({})",
+                validate::summarize(&issues)
+            ));
+            return;
+        }
 
-        if let Some(parent) = target.parent() {
-            if !parent.as_os_str().is_empty() {
-                if let Err(err) = fs::create_dir_all(parent) {
-                    self.status = Some(format!("Could not create folder: {}", err));
-                    return;
-                }
+        self.status = Some(match write_animation(&self.config, &target, format) {
+            Ok(path) => format!("Saved {}", path.display()),
+            Err(err) => err,
+        });
+    }
+
+    fn save_size_set(&mut self) {
+        let target = PathBuf::from(self.output_path.trim());
+        if target.as_os_str().is_empty() {
+            self.status = Some("Please enter an output file path.".to_string());
+            return;
+        }
+
+        let result = hinting::parse_size_list(&self.size_set).and_then(|sizes| {
+            write_size_set(
+                &self.config,
+                &target,
+                &sizes,
+                self.size_set_snap,
+                self.export_format,
+            )
+        });
+        self.status = Some(match result {
+            Ok(written) => format!("Saved {} files next to {}", written.len(), target.display()),
+            Err(err) => err,
+        });
+    }
+
+    fn save_xcursor(&mut self) {
+        let target = PathBuf::from(self.output_path.trim());
+        if target.as_os_str().is_empty() {
+            self.status = Some("Please enter an output file path.".to_string());
+            return;
+        }
+
+        let result = hinting::parse_size_list(&self.cursor_sizes)
+            .and_then(|sizes| write_xcursor(&self.config, &target, &sizes, self.cursor_theme));
+        self.status = Some(match result {
+            Ok(written) => format!("Saved {}", written[0].display()),
+            Err(err) => err,
+        });
+    }
+
+    fn import_cs2_code(&mut self) {
+        match cs2::decode_share_code(&self.cs2_import) {
+            Ok(settings) => {
+                let (config, warnings) = cs2::to_config(&settings);
+                self.config = config;
+                self.animation_playing = false;
+                self.status = Some(if warnings.is_empty() {
+                    "Imported CS2 crosshair".to_string()
+                } else {
+                    format!("Imported CS2 crosshair: {}", warnings.join("; "))
+                });
             }
+            Err(err) => self.status = Some(err),
+        }
+    }
+
+    fn save_current_svg(&mut self) {
+        let target = PathBuf::from(self.output_path.trim());
+        if target.as_os_str().is_empty() {
+            self.status = Some("Please enter an output file path.".to_string());
+            return;
+        }
+
+        if let Some(parent) = target.parent()
+            && !parent.as_os_str().is_empty()
+            && let Err(err) = fs::create_dir_all(parent)
+        {
+            self.status = Some(format!("Could not create folder: {}", err));
+            return;
+        }
+
+        let issues = validate::validate(&self.config);
+        if validate::has_errors(&issues) {
+            self.status = Some(format!(
+                "Fix the config before saving ({})",
+                validate::summarize(&issues)
+            ));
+            return;
         }
 
-        match svg::save(&target, &generate_svg(&self.config)) {
-            Ok(_) => self.status = Some(format!("Saved {}", target.display())),
-            Err(err) => self.status = Some(format!("Save failed: {}", err)),
+        match write_reticle(&self.config, &target, self.export_format) {
+            Ok(written) => {
+                let names: Vec<String> = written.iter().map(|p| p.display().to_string()).collect();
+                self.status = Some(format!("Saved {}", names.join(", ")))
+            }
+            Err(err) => self.status = Some(err),
         }
     }
 
     fn generate_batch(&mut self) {
         let output_root = PathBuf::from(self.batch_dir.trim());
-        match generate_batch_svgs(&self.config, self.csv_path.trim(), &output_root, false) {
-            Ok(count) => {
-                self.status = Some(format!(
-                    "Generated {} SVGs into {}",
-                    count,
+        let options = BatchOptions {
+            format: self.export_format,
+            strict: false,
+            contact_sheet: self.batch_contact_sheet,
+            gallery: self.batch_gallery,
+            atlas: self.batch_atlas,
+            verbose: false,
+        };
+        match generate_batch_svgs(&self.config, self.csv_path.trim(), &output_root, options) {
+            Ok(report) => {
+                let kind = if self.batch_atlas {
+                    "atlas"
+                } else {
+                    self.export_format.label()
+                };
+                let mut message = format!(
+                    "Generated {} {} reticles into {}",
+                    report.rendered,
+                    kind,
                     output_root.display()
-                ))
+                );
+                if let Some(manifest) = report.atlas.last() {
+                    message.push_str(&format!("; atlas manifest at {}", manifest.display()));
+                }
+                if let Some(gallery) = &report.gallery {
+                    message.push_str(&format!("; gallery at {}", gallery.display()));
+                }
+                if let Some(sheet) = report.contact_sheet.first() {
+                    message.push_str(&format!("; contact sheet at {}", sheet.display()));
+                }
+                if let Some(first) = report.skipped.first() {
+                    message.push_str(&format!(
+                        "; skipped {} invalid rows (first: {})",
+                        report.skipped.len(),
+                        first
+                    ));
+                }
+                self.status = Some(message)
             }
             Err(err) => self.status = Some(format!("Batch failed: {}", err)),
         }
@@ -852,66 +2533,619 @@ impl CrosshairApp {
         }
     }
 
-    fn load_profile(&mut self) {
-        let preferred = if !self.profile_name.trim().is_empty() {
-            Some(self.profile_name.trim().to_string())
-        } else {
-            self.selected_profile.clone()
-        };
+    fn open_svg(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_directory(user_output_dir())
+            .add_filter("SVG", &["svg"])
+            .pick_file()
+        else {
+            return;
+        };
+
+        match metadata::read_svg_config(&path) {
+            Ok(cfg) => {
+                self.config = cfg;
+                self.animation_playing = false;
+                if let Some(stem) = path.file_stem() {
+                    self.profile_name = stem.to_string_lossy().to_string();
+                }
+                let issues = validate::validate(&self.config);
+                self.status = Some(if issues.is_empty() {
+                    format!("Opened {}", path.display())
+                } else {
+                    format!(
+                        "Opened {} with {}",
+                        path.display(),
+                        validate::summarize(&issues)
+                    )
+                });
+            }
+            Err(err) => self.status = Some(err),
+        }
+    }
+
+    fn load_profile(&mut self) {
+        let preferred = if !self.profile_name.trim().is_empty() {
+            Some(self.profile_name.trim().to_string())
+        } else {
+            self.selected_profile.clone()
+        };
+
+        let name = match preferred {
+            Some(n) => n,
+            None => {
+                self.status = Some("Select or enter a profile name to load.".to_string());
+                return;
+            }
+        };
+
+        match load_profile_from_disk(&name) {
+            Ok(cfg) => {
+                self.config = cfg;
+                self.profile_name = name.clone();
+                self.selected_profile = Some(name.clone());
+                let issues = validate::validate(&self.config);
+                self.status = Some(if issues.is_empty() {
+                    format!("Loaded profile '{}'", name)
+                } else {
+                    format!(
+                        "Loaded profile '{}' with {}",
+                        name,
+                        validate::summarize(&issues)
+                    )
+                });
+            }
+            Err(err) => self.status = Some(err),
+        }
+    }
+
+    fn revert_defaults(&mut self) {
+        self.config = CrosshairConfig::default();
+        self.status = Some("Reverted to default settings.".to_string());
+    }
+
+    fn delete_profile(&mut self) {
+        let preferred = if !self.profile_name.trim().is_empty() {
+            Some(self.profile_name.trim().to_string())
+        } else {
+            self.selected_profile.clone()
+        };
+
+        let name = match preferred {
+            Some(n) => n,
+            None => {
+                self.status = Some("Select or enter a profile name to delete.".to_string());
+                return;
+            }
+        };
+
+        match profile_path(&name)
+            .and_then(|p| fs::remove_file(&p).map(|_| p).map_err(|e| e.to_string()))
+        {
+            Ok(_path) => {
+                self.status = Some(format!("Deleted profile '{}'", name));
+                if self.selected_profile.as_deref() == Some(&name) {
+                    self.selected_profile = None;
+                }
+                if self.profile_name.trim() == name {
+                    self.profile_name.clear();
+                }
+                self.refresh_profiles();
+            }
+            Err(err) => self.status = Some(format!("Delete failed: {}", err)),
+        }
+    }
+
+    fn draw_center_marker(&mut self, ui: &mut egui::Ui) {
+        let marker = &mut self.config.center_marker;
+        ui.horizontal(|ui| {
+            ui.label("Center marker");
+            egui::ComboBox::from_id_source("center_marker_shape")
+                .selected_text(marker.shape.label())
+                .show_ui(ui, |ui| {
+                    for shape in MarkerShape::ALL {
+                        ui.selectable_value(&mut marker.shape, shape, shape.label());
+                    }
+                });
+            if marker.shape != MarkerShape::None {
+                let mut rgba = tuple_to_rgba(marker.color);
+                if color_picker::color_edit_button_rgba(
+                    ui,
+                    &mut rgba,
+                    color_picker::Alpha::OnlyBlend,
+                )
+                .changed()
+                {
+                    marker.color = rgba_to_tuple(rgba);
+                }
+            }
+        });
+        if marker.shape == MarkerShape::None {
+            return;
+        }
+
+        ui.add(egui::Slider::new(&mut marker.size, 0.5..=256.0).text("Marker size"));
+        if marker.shape != MarkerShape::Dot {
+            ui.add(egui::Slider::new(&mut marker.thickness, 0.5..=64.0).text("Marker thickness"));
+        }
+        ui.add(egui::Slider::new(&mut marker.opacity, 0.0..=1.0).text("Marker opacity"));
+    }
+
+    // Returns true when the primary (first) ring's radius was edited.
+    fn draw_ring_list(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut primary_radius_changed = false;
+        let mut remove_idx = None;
+
+        ui.label("Rings");
+        for (idx, ring) in self.config.rings.iter_mut().enumerate() {
+            egui::CollapsingHeader::new(format!("Ring {}", idx + 1))
+                .id_source(("ring", idx))
+                .default_open(idx == 0)
+                .show(ui, |ui| {
+                    let response = ui.add(
+                        egui::Slider::new(&mut ring.outer_radius, 1.0..=MAX_RING_OUTER_RADIUS)
+                            .text("Outer radius"),
+                    );
+                    primary_radius_changed |= idx == 0 && response.changed();
+                    ui.add(egui::Slider::new(&mut ring.thickness, 1.0..=2048.0).text("Thickness"));
+                    ui.horizontal(|ui| {
+                        ui.label("Color");
+                        let mut rgba = tuple_to_rgba(ring.color);
+                        if color_picker::color_edit_button_rgba(
+                            ui,
+                            &mut rgba,
+                            color_picker::Alpha::OnlyBlend,
+                        )
+                        .changed()
+                        {
+                            ring.color = rgba_to_tuple(rgba);
+                        }
+                    });
+                    ui.add(egui::Slider::new(&mut ring.opacity, 0.0..=1.0).text("Opacity"));
+                    draw_gradient(
+                        ui,
+                        ("ring_gradient", idx),
+                        &mut ring.gradient,
+                        "Fade toward outer edge",
+                    );
+                    draw_ring_style(ui, idx, &mut ring.style);
+                    if ui.small_button("Remove ring").clicked() {
+                        remove_idx = Some(idx);
+                    }
+                });
+        }
+
+        if let Some(idx) = remove_idx {
+            self.config.rings.remove(idx);
+            if self.config.spoke_anchor_ring >= idx && self.config.spoke_anchor_ring > 0 {
+                self.config.spoke_anchor_ring -= 1;
+            }
+        }
+        if ui.button("Add ring").clicked() {
+            let next = self
+                .config
+                .rings
+                .last()
+                .map(|last| RingConfig {
+                    outer_radius: (last.inner_radius() - last.thickness).max(1.0),
+                    ..last.clone()
+                })
+                .unwrap_or_default();
+            self.config.rings.push(next);
+        }
+
+        if !self.config.rings.is_empty() {
+            egui::ComboBox::from_label("Spokes anchor to")
+                .selected_text(format!("Ring {}", self.config.spoke_anchor_ring + 1))
+                .show_ui(ui, |ui| {
+                    for idx in 0..self.config.rings.len() {
+                        ui.selectable_value(
+                            &mut self.config.spoke_anchor_ring,
+                            idx,
+                            format!("Ring {}", idx + 1),
+                        );
+                    }
+                });
+        }
+
+        primary_radius_changed
+    }
+
+    fn draw_symmetry(&mut self, ui: &mut egui::Ui) {
+        let spoke_count = self.config.spokes.len().max(1) as u32;
+        let symmetry = &mut self.config.symmetry;
+        let mut regenerate = false;
+
+        ui.horizontal(|ui| {
+            ui.label("Symmetry");
+            for mode in SymmetryMode::ALL {
+                // Entering rotational mode adopts the current spoke count so nothing is
+                // dropped; angles only change once the count or offset is edited.
+                if ui
+                    .selectable_value(&mut symmetry.mode, mode, mode.label())
+                    .changed()
+                    && mode == SymmetryMode::Rotational
+                {
+                    symmetry.count = spoke_count;
+                }
+            }
+        });
+        match symmetry.mode {
+            SymmetryMode::Off => {}
+            SymmetryMode::Rotational => {
+                regenerate |= ui
+                    .add(egui::Slider::new(&mut symmetry.count, 1..=64).text("Spoke count"))
+                    .changed();
+                let response = ui.add(
+                    egui::Slider::new(&mut symmetry.offset, 0.0..=360.0).text("Rotation offset"),
+                );
+                if response.changed() {
+                    symmetry.offset = snap_angle(symmetry.offset, symmetry.snap_increment);
+                    regenerate = true;
+                }
+            }
+            SymmetryMode::Mirror => {
+                let response = ui.add(
+                    egui::Slider::new(&mut symmetry.mirror_axis, 0.0..=180.0).text("Mirror axis"),
+                );
+                if response.changed() {
+                    symmetry.mirror_axis =
+                        snap_angle(symmetry.mirror_axis, symmetry.snap_increment);
+                }
+            }
+        }
+        ui.add(
+            egui::Slider::new(&mut symmetry.snap_increment, 0.0..=90.0)
+                .text("Angle snap (0 = off)"),
+        );
+
+        if regenerate {
+            apply_rotational_symmetry(&mut self.config);
+        }
+    }
+
+    fn draw_overlay(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Overlay");
+        let toggled = ui
+            .checkbox(
+                &mut self.overlay_enabled,
+                "Show click-through overlay on top of other windows",
+            )
+            .changed();
+        if toggled && self.overlay_enabled && self.overlay_anchor.is_none() {
+            self.overlay_anchor = window_center(ui.ctx());
+        }
+        ui.add(
+            egui::Slider::new(&mut self.overlay_scale, 0.25..=4.0)
+                .logarithmic(true)
+                .text("Scale"),
+        );
+        ui.horizontal(|ui| {
+            ui.label("Offset");
+            ui.add(
+                egui::DragValue::new(&mut self.overlay_offset[0])
+                    .speed(1.0)
+                    .prefix("x ")
+                    .suffix(" px"),
+            );
+            ui.add(
+                egui::DragValue::new(&mut self.overlay_offset[1])
+                    .speed(1.0)
+                    .prefix("y ")
+                    .suffix(" px"),
+            );
+            if ui.button("Reset").clicked() {
+                self.overlay_offset = [0.0, 0.0];
+            }
+        });
+        if ui.button("Use this window's monitor").clicked() {
+            self.overlay_anchor = window_center(ui.ctx());
+        }
+        match self.overlay_anchor {
+            Some(anchor) => ui.label(format!(
+                "Covers the monitor containing ({:.0}, {:.0}); the offset is from its center",
+                anchor.x, anchor.y
+            )),
+            None => ui.label(
+                "This system does not report window positions, so it picks the overlay's \
+                 monitor; move the reticle there with the offset",
+            ),
+        };
+    }
+
+    // Borderless, transparent, click-through window drawing `config` over whatever
+    // runs underneath. It goes fullscreen on the monitor containing the anchor, so the
+    // reticle is centered on that monitor without knowing where it sits on the desktop.
+    // The control panel stays in the main window.
+    fn show_overlay(&mut self, ctx: &egui::Context, config: &CrosshairConfig) {
+        let mut builder = egui::ViewportBuilder::default()
+            .with_title("Crosshair overlay")
+            .with_decorations(false)
+            .with_transparent(true)
+            .with_always_on_top()
+            .with_mouse_passthrough(true)
+            .with_resizable(false)
+            .with_taskbar(false)
+            .with_fullscreen(true);
+        if let Some(anchor) = self.overlay_anchor {
+            builder = builder.with_position(anchor);
+        }
+        // A fullscreen window doesn't follow position changes, so a new anchor opens
+        // a new window.
+        let id = egui::ViewportId::from_hash_of((
+            OVERLAY_VIEWPORT_ID,
+            self.overlay_anchor
+                .map(|anchor| [anchor.x.to_bits(), anchor.y.to_bits()]),
+        ));
+        let (scale, offset) = (self.overlay_scale, self.overlay_offset);
+        let raster = self
+            .raster_preview
+            .as_ref()
+            .filter(|_| self.preview_mode == PreviewMode::Raster)
+            .map(|preview| preview.texture.id());
+
+        let mut closed = false;
+        ctx.show_viewport_immediate(id, builder, |ctx, _class| {
+            egui::CentralPanel::default()
+                .frame(egui::Frame::none())
+                .show(ctx, |ui| {
+                    // Sizes are physical pixels; the overlay's monitor sets the scale.
+                    let pixels_per_point = ctx.pixels_per_point();
+                    let side = config.size as f32 * scale / pixels_per_point;
+                    let center =
+                        ui.max_rect().center() + vec2(offset[0], offset[1]) / pixels_per_point;
+                    let rect = egui::Rect::from_center_size(center, vec2(side, side));
+                    let painter = ui.painter_at(rect);
+                    match raster {
+                        Some(texture) => {
+                            painter.image(
+                                texture,
+                                rect,
+                                egui::Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+                                Color32::WHITE,
+                            );
+                        }
+                        None => paint_vector_preview(&painter, rect, config),
+                    }
+                });
+            closed = ctx.input(|i| i.viewport().close_requested());
+        });
+        if closed {
+            self.overlay_enabled = false;
+        }
+    }
+
+    fn draw_animation(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Animation");
+        let field_choices = animation::numeric_fields(&self.config);
+        let animation = &mut self.config.animation;
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut animation.enabled, "Animate");
+            let label = if self.animation_playing {
+                "Pause preview"
+            } else {
+                "Play preview"
+            };
+            if ui
+                .add_enabled(animation.enabled, egui::Button::new(label))
+                .clicked()
+            {
+                self.animation_playing = !self.animation_playing;
+                self.animation_started = ui.input(|i| i.time);
+            }
+        });
+        if !animation.enabled {
+            return;
+        }
+
+        ui.add(egui::Slider::new(&mut animation.duration, 0.1..=10.0).text("Cycle (s)"));
+        ui.add(egui::Slider::new(&mut animation.fps, 1..=60).text("Frames per second"));
+        ui.add(
+            egui::DragValue::new(&mut animation.loops)
+                .clamp_range(0..=1000)
+                .prefix("Export plays (0 = loop): "),
+        );
+
+        let mut preset = None;
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Add");
+            for option in AnimationPreset::ALL {
+                if ui.small_button(option.label()).clicked() {
+                    preset = Some(option);
+                }
+            }
+        });
+        if let Some(preset) = preset {
+            let track = preset.track(&self.config);
+            self.config.animation.tracks.push(track);
+        }
+
+        let mut remove_track = None;
+        for (idx, track) in self.config.animation.tracks.iter_mut().enumerate() {
+            let title = if track.field.is_empty() {
+                format!("Track {}", idx + 1)
+            } else {
+                format!("Track {}: {}", idx + 1, track.field)
+            };
+            egui::CollapsingHeader::new(title)
+                .id_source(("animation_track", idx))
+                .show(ui, |ui| {
+                    draw_animation_track(ui, idx, track, &field_choices);
+                    if ui.small_button("Remove track").clicked() {
+                        remove_track = Some(idx);
+                    }
+                });
+        }
+        if let Some(idx) = remove_track {
+            self.config.animation.tracks.remove(idx);
+        }
+        if ui.button("Add track").clicked() {
+            self.config.animation.tracks.push(AnimationTrack::keyed(
+                "",
+                false,
+                &[(0.0, 0.0, Easing::Linear)],
+            ));
+        }
 
-        let name = match preferred {
-            Some(n) => n,
-            None => {
-                self.status = Some("Select or enter a profile name to load.".to_string());
-                return;
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Export");
+            for format in AnimationFormat::ALL {
+                let mut button = ui.button(format.label());
+                if format == AnimationFormat::Svg {
+                    button = button.on_hover_text(
+                        "Stores a full copy of the reticle per distinct frame; long or high-fps cycles get large.",
+                    );
+                }
+                if button.clicked() {
+                    self.save_animation(format);
+                }
             }
-        };
+        });
+    }
 
-        match load_profile_from_disk(&name) {
-            Ok(cfg) => {
-                self.config = cfg;
-                self.profile_name = name.clone();
-                self.selected_profile = Some(name.clone());
-                self.status = Some(format!("Loaded profile '{}'", name));
+    fn add_spoke(&mut self) {
+        let symmetry = &self.config.symmetry;
+        match symmetry.mode {
+            SymmetryMode::Off => self.config.spokes.push(SpokeConfig::at(0.0)),
+            SymmetryMode::Rotational => {
+                self.config.symmetry.count += 1;
+                apply_rotational_symmetry(&mut self.config);
+            }
+            SymmetryMode::Mirror => {
+                // New spokes arrive as a pair straddling the axis.
+                let angle = normalize_angle(symmetry.mirror_axis - 90.0);
+                let partner = mirror_angle(symmetry.mirror_axis, angle);
+                self.config.spokes.push(SpokeConfig::at(angle));
+                if !same_angle(angle, partner) {
+                    self.config.spokes.push(SpokeConfig::at(partner));
+                }
             }
-            Err(err) => self.status = Some(err),
         }
     }
 
-    fn revert_defaults(&mut self) {
-        self.config = CrosshairConfig::default();
-        self.status = Some("Reverted to default settings.".to_string());
-    }
+    fn draw_spoke_table(&mut self, ui: &mut egui::Ui) {
+        let defaults = self.config.clone();
+        let rotational = defaults.symmetry.mode == SymmetryMode::Rotational;
+        let snap = defaults.symmetry.snap_increment;
+        let mut remove_idx = None;
 
-    fn delete_profile(&mut self) {
-        let preferred = if !self.profile_name.trim().is_empty() {
-            Some(self.profile_name.trim().to_string())
-        } else {
-            self.selected_profile.clone()
-        };
+        egui::Grid::new("spoke_table")
+            .striped(true)
+            .spacing([6.0, 4.0])
+            .show(ui, |ui| {
+                for header in ["Angle", "Base", "Tip", "Gap", "Tip r", "Color", "Style", ""] {
+                    ui.label(header);
+                }
+                ui.end_row();
+
+                for (idx, spoke) in self.config.spokes.iter_mut().enumerate() {
+                    let response = ui.add_enabled(
+                        !rotational,
+                        egui::DragValue::new(&mut spoke.angle)
+                            .speed(0.5)
+                            .clamp_range(0.0..=360.0)
+                            .suffix("°"),
+                    );
+                    if response.changed() {
+                        spoke.angle = normalize_angle(snap_angle(spoke.angle, snap));
+                    }
+                    override_drag(
+                        ui,
+                        &mut spoke.base_width,
+                        defaults.spoke_base_width,
+                        0.0..=2048.0,
+                    );
+                    override_drag(
+                        ui,
+                        &mut spoke.tip_width,
+                        defaults.spoke_tip_width,
+                        0.0..=1024.0,
+                    );
+                    override_drag(
+                        ui,
+                        &mut spoke.gap_from_ring,
+                        defaults.gap_from_ring,
+                        0.0..=2048.0,
+                    );
+                    override_drag(
+                        ui,
+                        &mut spoke.tip_radius,
+                        defaults.center_gap_radius,
+                        0.0..=2048.0,
+                    );
+
+                    let mut rgba = tuple_to_rgba(spoke.color(&defaults));
+                    if color_picker::color_edit_button_rgba(
+                        ui,
+                        &mut rgba,
+                        color_picker::Alpha::OnlyBlend,
+                    )
+                    .changed()
+                    {
+                        spoke.color = Some(rgba_to_tuple(rgba));
+                    }
 
-        let name = match preferred {
-            Some(n) => n,
-            None => {
-                self.status = Some("Select or enter a profile name to delete.".to_string());
-                return;
-            }
-        };
+                    let selected = spoke.style.map_or("Inherit", |style| style.label());
+                    egui::ComboBox::from_id_source(("spoke_style_override", idx))
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            if ui
+                                .selectable_label(spoke.style.is_none(), "Inherit")
+                                .clicked()
+                            {
+                                spoke.style = None;
+                            }
+                            for option in SpokeStyle::ALL {
+                                let current = spoke.style.is_some_and(|s| s.same_kind(&option));
+                                if ui.selectable_label(current, option.label()).clicked()
+                                    && !current
+                                {
+                                    // Start from the global parameters when the kind matches.
+                                    spoke.style =
+                                        Some(if defaults.spoke_style.same_kind(&option) {
+                                            defaults.spoke_style
+                                        } else {
+                                            option
+                                        });
+                                }
+                            }
+                        });
+
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(spoke.has_overrides(), egui::Button::new("Reset").small())
+                            .clicked()
+                        {
+                            *spoke = SpokeConfig::at(spoke.angle);
+                        }
+                        if ui.small_button("Remove").clicked() {
+                            remove_idx = Some(idx);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
 
-        match profile_path(&name)
-            .and_then(|p| fs::remove_file(&p).map(|_| p).map_err(|e| e.to_string()))
+        if self.config.symmetry.mode == SymmetryMode::Mirror
+            && let Some(edited) = (0..self.config.spokes.len())
+                .find(|&i| defaults.spokes.get(i) != Some(&self.config.spokes[i]))
         {
-            Ok(_path) => {
-                self.status = Some(format!("Deleted profile '{}'", name));
-                if self.selected_profile.as_deref() == Some(&name) {
-                    self.selected_profile = None;
-                }
-                if self.profile_name.trim() == name {
-                    self.profile_name.clear();
-                }
-                self.refresh_profiles();
+            apply_mirror_edit(&mut self.config, &defaults.spokes, edited);
+        }
+        if let Some(idx) = remove_idx {
+            self.config.spokes.remove(idx);
+            if rotational {
+                self.config.symmetry.count = self.config.spokes.len().max(1) as u32;
+                apply_rotational_symmetry(&mut self.config);
+            }
+        }
+
+        for (idx, spoke) in self.config.spokes.iter_mut().enumerate() {
+            if let Some(style) = spoke.style.as_mut() {
+                egui::CollapsingHeader::new(format!("Spoke {} style", idx + 1))
+                    .id_source(("spoke_style_params", idx))
+                    .show(ui, |ui| draw_spoke_style_params(ui, style));
             }
-            Err(err) => self.status = Some(format!("Delete failed: {}", err)),
         }
     }
 
@@ -950,42 +3184,63 @@ impl CrosshairApp {
                 self.delete_profile();
             }
         });
+        if ui.button("Open SVG").clicked() {
+            self.open_svg();
+        }
         ui.separator();
 
         ui.heading("Crosshair Settings");
+        draw_issues(ui, &validate::validate(&self.config));
         let mut canvas_changed = false;
+        let pixel_units = self.config.units == GeometryUnits::Pixels;
         ui.horizontal(|ui| {
             let response = ui.add(
                 egui::Slider::new(&mut self.config.size, MIN_CANVAS_SIZE..=MAX_CANVAS_SIZE)
                     .text("Canvas size"),
             );
             canvas_changed |= response.changed();
-            ui.toggle_value(&mut self.chain_canvas_and_radius, "Chain size <-> radius");
+            // Design units already keep the ring proportional to the canvas.
+            let chain =
+                egui::SelectableLabel::new(self.chain_canvas_and_radius, "Chain size <-> radius");
+            if ui.add_enabled(pixel_units, chain).clicked() {
+                self.chain_canvas_and_radius = !self.chain_canvas_and_radius;
+            }
+        });
+        ui.horizontal(|ui| {
+            let mut units = self.config.units;
+            egui::ComboBox::from_label("Units")
+                .selected_text(units.label())
+                .show_ui(ui, |ui| {
+                    for option in [GeometryUnits::Design, GeometryUnits::Pixels] {
+                        ui.selectable_value(&mut units, option, option.label());
+                    }
+                });
+            self.config.set_units(units);
+            if units == GeometryUnits::Design {
+                ui.add(
+                    egui::DragValue::new(&mut self.config.design_size)
+                        .speed(1.0)
+                        .clamp_range(1.0..=MAX_CANVAS_SIZE as f64)
+                        .prefix("Design size: "),
+                );
+            }
         });
 
-        let mut ring_radius_changed = false;
-        let ring_response = ui.add(
-            egui::Slider::new(
-                &mut self.config.ring_outer_radius,
-                1.0..=MAX_RING_OUTER_RADIUS,
-            )
-            .text("Ring outer radius"),
-        );
-        ring_radius_changed |= ring_response.changed();
+        let ring_radius_changed = self.draw_ring_list(ui);
 
-        if self.chain_canvas_and_radius {
+        let border_radius = canvas_border_radius(&self.config);
+        if pixel_units
+            && self.chain_canvas_and_radius
+            && let Some(primary) = self.config.rings.first_mut()
+        {
             if canvas_changed {
-                self.config.ring_outer_radius =
-                    canvas_border_radius(self.config.size).clamp(0.0, MAX_RING_OUTER_RADIUS);
+                primary.outer_radius = border_radius.clamp(0.0, MAX_RING_OUTER_RADIUS);
             } else if ring_radius_changed {
-                let new_size = (self.config.ring_outer_radius * 2.0).round() as u32;
+                let new_size = (primary.outer_radius * 2.0).round() as u32;
                 self.config.size = new_size.clamp(MIN_CANVAS_SIZE, MAX_CANVAS_SIZE);
             }
         }
 
-        ui.add(
-            egui::Slider::new(&mut self.config.ring_thickness, 1.0..=2048.0).text("Ring thickness"),
-        );
         ui.add(
             egui::Slider::new(&mut self.config.gap_from_ring, 0.0..=2048.0)
                 .text("Arm gap from ring"),
@@ -994,6 +3249,7 @@ impl CrosshairApp {
             egui::Slider::new(&mut self.config.center_gap_radius, 0.0..=2048.0)
                 .text("Center gap radius"),
         );
+        self.draw_center_marker(ui);
         ui.separator();
 
         ui.add(
@@ -1004,21 +3260,14 @@ impl CrosshairApp {
             egui::Slider::new(&mut self.config.spoke_tip_width, 0.0..=1024.0)
                 .text("Spoke tip width"),
         );
+        draw_spoke_style(ui, "spoke_style", &mut self.config.spoke_style);
         ui.separator();
 
         ui.add(egui::Slider::new(&mut self.config.blur_radius, 0.0..=12.0).text("Blur radius"));
         ui.add(egui::Slider::new(&mut self.config.glow_radius, 0.0..=20.0).text("Glow radius"));
-        ui.label("Blur/glow values are kept with the config; current renderer draws crisp edges.");
+        ui.label("Blur/glow are written as SVG filters; the live preview draws crisp edges.");
         ui.separator();
 
-        ui.label("Rim color");
-        let mut rim_rgba = tuple_to_rgba(self.config.rim_color);
-        if color_picker::color_edit_button_rgba(ui, &mut rim_rgba, color_picker::Alpha::OnlyBlend)
-            .changed()
-        {
-            self.config.rim_color = rgba_to_tuple(rim_rgba);
-        }
-
         ui.label("Arm color");
         let mut arm_rgba = tuple_to_rgba(self.config.arm_color);
         if color_picker::color_edit_button_rgba(ui, &mut arm_rgba, color_picker::Alpha::OnlyBlend)
@@ -1026,57 +3275,124 @@ impl CrosshairApp {
         {
             self.config.arm_color = rgba_to_tuple(arm_rgba);
         }
+        draw_gradient(
+            ui,
+            "arm_gradient",
+            &mut self.config.arm_gradient,
+            "Fade toward tip",
+        );
 
-        ui.separator();
-        ui.label("Spoke angles (degrees)");
-        let mut remove_idx = None;
-        for (idx, angle) in self.config.angles.iter_mut().enumerate() {
-            ui.horizontal(|ui| {
-                ui.add(
-                    egui::DragValue::new(angle)
-                        .speed(0.5)
-                        .clamp_range(0.0..=360.0)
-                        .suffix("°"),
-                );
-                if ui.small_button("Remove").clicked() {
-                    remove_idx = Some(idx);
-                }
-            });
-        }
-        if let Some(idx) = remove_idx {
-            self.config.angles.remove(idx);
+        let outline = &mut self.config.contrast_outline;
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut outline.enabled, "Contrast outline");
+            let mut rgba = tuple_to_rgba(outline.color);
+            if color_picker::color_edit_button_rgba(ui, &mut rgba, color_picker::Alpha::OnlyBlend)
+                .changed()
+            {
+                outline.color = rgba_to_tuple(rgba);
+            }
+        });
+        if outline.enabled {
+            ui.add(egui::Slider::new(&mut outline.width, 0.0..=64.0).text("Outline width"));
+            let mut alpha = outline.color.3;
+            if ui
+                .add(egui::Slider::new(&mut alpha, 0.0..=1.0).text("Outline alpha"))
+                .changed()
+            {
+                outline.color.3 = alpha;
+            }
         }
-        if ui.button("Add angle").clicked() {
-            self.config.angles.push(0.0);
+
+        ui.separator();
+        ui.label("Spokes");
+        self.draw_symmetry(ui);
+        ui.label(
+            "Values follow the global settings above until edited; Reset clears a row's overrides.",
+        );
+        self.draw_spoke_table(ui);
+        if ui.button("Add spoke").clicked() {
+            self.add_spoke();
         }
 
+        ui.separator();
+        self.draw_animation(ui);
+
+        ui.separator();
+        self.draw_overlay(ui);
+
         ui.separator();
         ui.heading("Export");
         ui.horizontal(|ui| {
-            ui.label("Preview SVG path");
+            ui.label("Format");
+            for format in ExportFormat::ALL {
+                ui.selectable_value(&mut self.export_format, format, format.label());
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Output path");
             ui.text_edit_singleline(&mut self.output_path);
-            if ui.button("Pick folder").clicked() {
-                if let Some(folder) = rfd::FileDialog::new()
+            if ui.button("Pick folder").clicked()
+                && let Some(folder) = rfd::FileDialog::new()
                     .set_directory(user_output_dir())
                     .pick_folder()
-                {
-                    let filename = PathBuf::from(&self.output_path)
-                        .file_name()
-                        .map(|s| s.to_owned())
-                        .unwrap_or_else(|| "reticle-preview.svg".into());
-                    let new_path = folder.join(filename);
-                    self.output_path = new_path.to_string_lossy().to_string();
-                }
+            {
+                let filename = PathBuf::from(&self.output_path)
+                    .file_name()
+                    .map(|s| s.to_owned())
+                    .unwrap_or_else(|| "reticle-preview.svg".into());
+                let new_path = folder.join(filename);
+                self.output_path = new_path.to_string_lossy().to_string();
             }
         });
-        if ui.button("Save current SVG").clicked() {
+        if ui.button("Save current reticle").clicked() {
             self.save_current_svg();
         }
+        ui.horizontal(|ui| {
+            ui.label("Sizes (px)");
+            ui.text_edit_singleline(&mut self.size_set);
+        });
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.size_set_snap, "Snap to pixel grid");
+            if ui.button("Save size set").clicked() {
+                self.save_size_set();
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Cursor sizes (px)");
+            ui.text_edit_singleline(&mut self.cursor_sizes);
+        });
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.cursor_theme, "Write cursor theme folder");
+            if ui.button("Save Xcursor").clicked() {
+                self.save_xcursor();
+            }
+        });
+
+        ui.separator();
+        ui.heading("CS2 share code");
+        let (settings, warnings) = cs2::from_config(&self.config);
+        let code = cs2::encode_share_code(&settings);
+        ui.horizontal(|ui| {
+            ui.monospace(&code);
+            if ui.button("Copy").clicked() {
+                ui.ctx().copy_text(code.clone());
+            }
+        });
+        for warning in &warnings {
+            ui.colored_label(ui.visuals().warn_fg_color, warning);
+        }
+        ui.horizontal(|ui| {
+            ui.label("Import");
+            ui.text_edit_singleline(&mut self.cs2_import);
+            if ui.button("Load").clicked() {
+                self.import_cs2_code();
+            }
+        });
 
         ui.separator();
         ui.heading("Batch from CSV");
         ui.horizontal(|ui| {
-            ui.label("CSV with rim,arm hex pairs");
+            ui.label("CSV rows: rim,arm[,outline hex[,outline width]]");
             if ui.button("Open default path").clicked() {
                 self.open_default_csv_directory();
             }
@@ -1085,15 +3401,20 @@ impl CrosshairApp {
         ui.label("Output directory");
         ui.horizontal(|ui| {
             ui.text_edit_singleline(&mut self.batch_dir);
-            if ui.button("Pick folder").clicked() {
-                if let Some(folder) = rfd::FileDialog::new()
+            if ui.button("Pick folder").clicked()
+                && let Some(folder) = rfd::FileDialog::new()
                     .set_directory(user_output_dir())
                     .pick_folder()
-                {
-                    self.batch_dir = folder.to_string_lossy().to_string();
-                }
+            {
+                self.batch_dir = folder.to_string_lossy().to_string();
             }
         });
+        ui.checkbox(&mut self.batch_contact_sheet, "Write contact sheet");
+        ui.checkbox(&mut self.batch_gallery, "Write HTML gallery");
+        ui.checkbox(
+            &mut self.batch_atlas,
+            "Pack into atlas instead of loose files",
+        );
         if ui.button("Generate full set").clicked() {
             self.generate_batch();
         }
@@ -1106,6 +3427,12 @@ impl CrosshairApp {
 }
 
 impl eframe::App for CrosshairApp {
+    // eframe clears every viewport with this color, so it stays transparent for the
+    // overlay; the main window's panels paint over it.
+    fn clear_color(&self, _visuals: &egui::Visuals) -> [f32; 4] {
+        [0.0; 4]
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::SidePanel::left("controls")
             .resizable(true)
@@ -1114,12 +3441,260 @@ impl eframe::App for CrosshairApp {
                 self.draw_controls(ui);
             });
 
+        let preview_config = self.preview_config(ctx);
+        if self.preview_mode == PreviewMode::Raster {
+            self.refresh_raster_preview(ctx, &preview_config);
+        }
+        if self.overlay_enabled {
+            self.show_overlay(ctx, &preview_config);
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Live preview");
             ui.label("Adjust settings on the left to see the updated reticle.");
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.preview_mode, PreviewMode::Vector, "Vector");
+                ui.selectable_value(
+                    &mut self.preview_mode,
+                    PreviewMode::Raster,
+                    "Rasterized SVG",
+                );
+                if self.preview_mode == PreviewMode::Raster {
+                    ui.checkbox(&mut self.diff_preview, "Diff against vector")
+                        .on_hover_text("Magenta marks pixels where the vector preview and the SVG render differ; blur and glow are left out of both.");
+                }
+            });
             ui.add_space(8.0);
-            draw_crosshair_preview(ui, &self.config);
+
+            let raster = match self.preview_mode {
+                PreviewMode::Vector => None,
+                PreviewMode::Raster => {
+                    if let Some(err) = &self.raster_error {
+                        ui.label(err);
+                    }
+                    self.raster_preview.as_ref()
+                }
+            };
+            let diff = raster
+                .filter(|_| self.diff_preview)
+                .and_then(|preview| preview.diff.as_ref());
+            draw_crosshair_preview(
+                ui,
+                &preview_config,
+                raster.map(|preview| &preview.texture),
+                diff,
+            );
+        });
+    }
+}
+
+fn draw_ring_style(ui: &mut egui::Ui, idx: usize, style: &mut RingStyle) {
+    egui::ComboBox::from_id_source(("ring_style", idx))
+        .selected_text(style.label())
+        .show_ui(ui, |ui| {
+            for option in [
+                RingStyle::Full,
+                RingStyle::segmented_default(),
+                RingStyle::spoke_gaps_default(),
+            ] {
+                let selected = std::mem::discriminant(style) == std::mem::discriminant(&option);
+                if ui.selectable_label(selected, option.label()).clicked() && !selected {
+                    *style = option;
+                }
+            }
+        });
+
+    match style {
+        RingStyle::Full => {}
+        RingStyle::Segmented {
+            start_angle,
+            end_angle,
+            count,
+            gap,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("From");
+                ui.add(
+                    egui::DragValue::new(start_angle)
+                        .speed(0.5)
+                        .clamp_range(0.0..=360.0)
+                        .suffix("°"),
+                );
+                ui.label("to");
+                ui.add(
+                    egui::DragValue::new(end_angle)
+                        .speed(0.5)
+                        .clamp_range(0.0..=360.0)
+                        .suffix("°"),
+                );
+            });
+            ui.add(egui::Slider::new(count, 1..=64).text("Segments"));
+            ui.add(egui::Slider::new(gap, 0.0..=180.0).text("Gap (°)"));
+        }
+        RingStyle::SpokeGaps { gap } => {
+            ui.add(egui::Slider::new(gap, 0.0..=180.0).text("Gap per spoke (°)"));
+        }
+    }
+}
+
+fn draw_animation_track(
+    ui: &mut egui::Ui,
+    idx: usize,
+    track: &mut AnimationTrack,
+    field_choices: &[String],
+) {
+    ui.horizontal(|ui| {
+        ui.label("Field");
+        ui.text_edit_singleline(&mut track.field);
+        egui::ComboBox::from_id_source(("animation_field", idx))
+            .selected_text("Pick")
+            .show_ui(ui, |ui| {
+                for field in field_choices {
+                    if ui.selectable_label(track.field == *field, field).clicked() {
+                        track.field = field.clone();
+                    }
+                }
+            });
+    });
+    if !track.field.is_empty() && !track.is_valid_field() {
+        ui.colored_label(ui.visuals().warn_fg_color, "Not an animatable field path.");
+    }
+    ui.checkbox(&mut track.relative, "Add to the configured value");
+
+    let mut remove_key = None;
+    egui::Grid::new(("animation_keys", idx))
+        .spacing([6.0, 4.0])
+        .show(ui, |ui| {
+            for header in ["Time (s)", "Value", "Easing", ""] {
+                ui.label(header);
+            }
+            ui.end_row();
+
+            for (key_idx, key) in track.keyframes.iter_mut().enumerate() {
+                ui.add(
+                    egui::DragValue::new(&mut key.time)
+                        .speed(0.01)
+                        .clamp_range(0.0..=600.0),
+                );
+                ui.add(egui::DragValue::new(&mut key.value).speed(0.1));
+                egui::ComboBox::from_id_source(("animation_easing", idx, key_idx))
+                    .selected_text(key.easing.label())
+                    .show_ui(ui, |ui| {
+                        for easing in Easing::ALL {
+                            ui.selectable_value(&mut key.easing, easing, easing.label());
+                        }
+                    });
+                if ui.small_button("Remove").clicked() {
+                    remove_key = Some(key_idx);
+                }
+                ui.end_row();
+            }
+        });
+    if let Some(key_idx) = remove_key {
+        track.keyframes.remove(key_idx);
+    }
+    if ui.small_button("Add keyframe").clicked() {
+        let next = track.keyframes.last().copied().unwrap_or_default();
+        track.keyframes.push(Keyframe {
+            time: next.time + 0.5,
+            ..next
+        });
+    }
+}
+
+fn draw_issues(ui: &mut egui::Ui, issues: &[validate::Issue]) {
+    for issue in issues {
+        let color = match issue.severity {
+            validate::Severity::Error => ui.visuals().error_fg_color,
+            validate::Severity::Warning => ui.visuals().warn_fg_color,
+        };
+        ui.colored_label(color, issue.to_string());
+    }
+}
+
+fn draw_spoke_style(ui: &mut egui::Ui, id: impl std::hash::Hash, style: &mut SpokeStyle) {
+    egui::ComboBox::from_id_source(id)
+        .selected_text(format!("Spoke style: {}", style.label()))
+        .show_ui(ui, |ui| {
+            for option in SpokeStyle::ALL {
+                let selected = style.same_kind(&option);
+                if ui.selectable_label(selected, option.label()).clicked() && !selected {
+                    *style = option;
+                }
+            }
+        });
+    draw_spoke_style_params(ui, style);
+}
+
+fn draw_spoke_style_params(ui: &mut egui::Ui, style: &mut SpokeStyle) {
+    match style {
+        SpokeStyle::Tapered { bulge } => {
+            ui.add(egui::Slider::new(bulge, -0.5..=0.5).text("Side bulge"));
+        }
+        SpokeStyle::Razor { pinch } => {
+            ui.add(egui::Slider::new(pinch, 0.0..=1.0).text("Pinch width"));
+        }
+        SpokeStyle::Rectangle { corner_radius } => {
+            ui.add(egui::Slider::new(corner_radius, 0.0..=512.0).text("Corner radius"));
+        }
+        SpokeStyle::Triangle { inverted } => {
+            ui.checkbox(inverted, "Point at ring end");
+        }
+        SpokeStyle::Chevron { thickness } => {
+            ui.add(egui::Slider::new(thickness, 0.5..=512.0).text("Arm thickness"));
+        }
+        SpokeStyle::RoundedCap { round_base } => {
+            ui.checkbox(round_base, "Round ring end too");
+        }
+    }
+}
+
+fn draw_gradient(
+    ui: &mut egui::Ui,
+    id: impl std::hash::Hash,
+    gradient: &mut Option<GradientFill>,
+    label: &str,
+) {
+    let mut enabled = gradient.is_some();
+    if ui.checkbox(&mut enabled, label).changed() {
+        *gradient = enabled.then(GradientFill::default);
+    }
+    let Some(fill) = gradient else {
+        return;
+    };
+
+    ui.push_id(id, |ui| {
+        ui.add(egui::Slider::new(&mut fill.end_alpha, 0.0..=1.0).text("End alpha"));
+        ui.horizontal(|ui| {
+            let mut blend = fill.end_rgb.is_some();
+            if ui.checkbox(&mut blend, "Blend to color").changed() {
+                fill.end_rgb = blend.then_some((255, 255, 255));
+            }
+            if let Some((r, g, b)) = fill.end_rgb.as_mut() {
+                let mut rgb = [*r, *g, *b];
+                if ui.color_edit_button_srgb(&mut rgb).changed() {
+                    (*r, *g, *b) = (rgb[0], rgb[1], rgb[2]);
+                }
+            }
         });
+    });
+}
+
+// Edits an inherited value in place; the first change turns it into an override.
+fn override_drag(
+    ui: &mut egui::Ui,
+    value: &mut Option<f64>,
+    inherited: f64,
+    range: std::ops::RangeInclusive<f64>,
+) {
+    let mut current = value.unwrap_or(inherited);
+    let response = ui.add(
+        egui::DragValue::new(&mut current)
+            .speed(0.25)
+            .clamp_range(range),
+    );
+    if response.changed() {
+        *value = Some(current);
     }
 }
 
@@ -1132,7 +3707,10 @@ fn run_gui() -> Result<(), eframe::Error> {
         viewport: egui::ViewportBuilder::default()
             .with_inner_size([1000.0, 700.0])
             .with_min_inner_size([800.0, 600.0])
-            .with_icon(app_icon()),
+            .with_icon(app_icon())
+            // eframe picks one GL config for all windows from the root viewport, and
+            // the overlay needs one with an alpha channel.
+            .with_transparent(true),
         ..Default::default()
     };
 
@@ -1143,23 +3721,176 @@ fn run_gui() -> Result<(), eframe::Error> {
     )
 }
 
-fn run_batch() -> Result<(), Box<dyn std::error::Error>> {
-    let config = CrosshairConfig::default();
+fn arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|idx| args.get(idx + 1))
+        .map(|value| value.as_str())
+}
+
+fn run_batch(
+    config: &CrosshairConfig,
+    options: BatchOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
     let out_dir = user_output_dir();
     let csv_path = default_csv_path_string();
-    println!("Generating SVGs from {} ...", csv_path);
-    let count = generate_batch_svgs(&config, &csv_path, &out_dir, true)?;
-    println!("Generated {} SVG crosshairs.", count);
+    let kind = if options.atlas {
+        "atlas"
+    } else {
+        options.format.label()
+    };
+    println!("Generating {} from {} ...", kind, csv_path);
+    let report = generate_batch_svgs(config, &csv_path, &out_dir, options)?;
+    println!("Generated {} {} crosshairs.", report.rendered, kind);
+    if !report.skipped.is_empty() {
+        println!("Skipped {} invalid rows.", report.skipped.len());
+    }
     Ok(())
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
+    // --open-svg reads the config embedded in an exported SVG; it becomes the batch
+    // base config, is saved with --profile, or is printed as profile JSON.
+    let opened = arg_value(&args, "--open-svg")
+        .map(|path| metadata::read_svg_config(Path::new(path)))
+        .transpose()?;
+
     if args.iter().any(|arg| arg == "--batch") {
-        run_batch()?;
+        let format = match arg_value(&args, "--format") {
+            Some(raw) => ExportFormat::parse(raw).ok_or_else(|| {
+                format!("Unknown format '{}': use svg, png, both, ico or cur", raw)
+            })?,
+            None => ExportFormat::Svg,
+        };
+        let flag = |name: &str| args.iter().any(|arg| arg == name);
+        let options = BatchOptions {
+            format,
+            // --strict stops at the first invalid row instead of skipping it.
+            strict: flag("--strict"),
+            // --contact-sheet also writes one SVG + PNG grid of every rendered reticle.
+            contact_sheet: flag("--contact-sheet"),
+            // --gallery writes index.html linking every rendered reticle.
+            gallery: flag("--gallery"),
+            // --atlas packs everything into atlas-<n>.png pages plus atlas.json.
+            atlas: flag("--atlas"),
+            verbose: true,
+        };
+        run_batch(&opened.unwrap_or_default(), options)?;
+        return Ok(());
+    }
+    if let Some(config) = opened {
+        match arg_value(&args, "--profile") {
+            Some(name) => {
+                let path = save_profile_to_disk(&config, name)?;
+                println!("Saved profile to {}", path.display());
+            }
+            None => println!("{}", serde_json::to_string_pretty(&config)?),
+        }
         return Ok(());
     }
 
     run_gui()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // A single-ring profile from before rings, units and spoke styles existed.
+    fn legacy_profile(tip_width: f64) -> serde_json::Value {
+        json!({
+            "size": 128,
+            "ring_outer_radius": 50.0,
+            "ring_thickness": 4.0,
+            "rim_color": [255, 255, 255, 0.8],
+            "arm_color": [0, 0, 0, 1.0],
+            "gap_from_ring": 6.0,
+            "center_gap_radius": 2.0,
+            "spoke_base_width": 8.0,
+            "spoke_tip_width": tip_width,
+            "angles": [0.0, 90.0, 180.0, 270.0],
+            "blur_radius": 0.0,
+            "glow_radius": 0.0
+        })
+    }
+
+    #[test]
+    fn legacy_profile_migrates_to_current_layout() {
+        let config = config_from_value(legacy_profile(0.0)).expect("legacy profile loads");
+
+        assert_eq!(config.rings.len(), 1);
+        let ring = &config.rings[0];
+        assert_eq!(ring.outer_radius, 50.0);
+        assert_eq!(ring.thickness, 4.0);
+        assert_eq!(ring.color, (255, 255, 255, 0.8));
+        assert_eq!(ring.opacity, RingConfig::default().opacity);
+
+        // Pixel lengths become design units on a canvas of the old size.
+        assert!(config.units == GeometryUnits::Design);
+        assert_eq!(config.design_size, 128.0);
+        assert_eq!(config.design_extent(), 128.0);
+
+        assert!(config.spoke_style == SpokeStyle::Razor { pinch: 0.18 });
+        let angles: Vec<f64> = config.spokes.iter().map(|spoke| spoke.angle).collect();
+        assert_eq!(angles, [0.0, 90.0, 180.0, 270.0]);
+        assert!(
+            config
+                .spokes
+                .iter()
+                .all(|spoke| { *spoke == SpokeConfig::at(spoke.angle) })
+        );
+    }
+
+    #[test]
+    fn legacy_tip_width_picks_style_per_spoke() {
+        // Per-spoke overrides arrived before spoke styles, under `spokes`.
+        let mut profile = legacy_profile(1.5);
+        let obj = profile.as_object_mut().unwrap();
+        obj.remove("angles");
+        obj.insert(
+            "spokes".to_string(),
+            json!([
+                { "angle": 45.0 },
+                { "angle": 135.0, "tip_width": 0.005 },
+            ]),
+        );
+        let config = config_from_value(profile).expect("legacy profile loads");
+
+        assert!(config.spoke_style == SpokeStyle::default());
+        assert_eq!(config.spokes.len(), 2);
+        assert!(config.spokes[0].style.is_none());
+        assert!(config.spokes[1].style == Some(SpokeStyle::Razor { pinch: 0.18 }));
+        assert_eq!(config.spokes[1].tip_width, Some(0.005));
+    }
+
+    #[test]
+    fn current_profile_is_left_alone() {
+        let config = CrosshairConfig {
+            units: GeometryUnits::Pixels,
+            spoke_style: SpokeStyle::Razor { pinch: 0.3 },
+            ..CrosshairConfig::default()
+        };
+        let value = serde_json::to_value(&config).unwrap();
+
+        let loaded = config_from_value(value.clone()).expect("current profile loads");
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), value);
+    }
+
+    #[test]
+    fn spoke_glow_takes_the_spoke_color() {
+        let mut config = CrosshairConfig::default();
+        let svg = generate_bare_svg(&config).to_string();
+        assert!(svg.contains("filter=\"url(#xh-arms-fx)\""));
+        assert!(!svg.contains("xh-arms-fx-1"));
+
+        config.spokes[1].color = Some((255, 0, 0, 1.0));
+        let svg = generate_bare_svg(&config).to_string();
+        assert!(svg.contains("flood-color=\"rgb(0,0,0)\""));
+        assert!(svg.contains("flood-color=\"rgb(255,0,0)\""));
+        assert_eq!(svg.matches("filter=\"url(#xh-arms-fx-1)\"").count(), 1);
+        assert_eq!(svg.matches("filter=\"url(#xh-arms-fx-0)\"").count(), 3);
+    }
+}
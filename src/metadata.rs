@@ -0,0 +1,154 @@
+// The source config embedded in exported SVGs.
+// Every document carries `<metadata id="xh-config">` holding a small JSON envelope
+// with the config and a format version, so any exported file can be reopened as a
+// profile. Profile migrations still run on the way back in.
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Value, json};
+use svg::Node;
+use svg::node::Text;
+use svg::node::element::Element;
+use svg::node::element::tag::Type;
+use svg::parser::Event;
+
+use crate::{CrosshairConfig, config_from_value};
+
+const METADATA_ID: &str = "xh-config";
+// Bump when the envelope layout changes; config fields are handled by `migrate_profile`.
+const FORMAT_VERSION: u64 = 1;
+
+fn escape_text(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn unescape_text(raw: &str) -> String {
+    raw.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+pub(crate) fn config_element(config: &CrosshairConfig) -> Element {
+    let envelope = json!({
+        "format_version": FORMAT_VERSION,
+        "config": config,
+    });
+    let mut element = Element::new("metadata");
+    element.assign("id", METADATA_ID);
+    element.append(Text::new(escape_text(&envelope.to_string())));
+    element
+}
+
+// Pulls the embedded config out of SVG source.
+pub(crate) fn config_from_svg(source: &str) -> Result<CrosshairConfig, String> {
+    let parser = svg::read(source).map_err(|e| format!("Parse failed: {}", e))?;
+    let mut inside = false;
+    let mut text = String::new();
+    for event in parser {
+        match event {
+            Event::Tag("metadata", Type::Start, attrs) => {
+                inside = attrs
+                    .get("id")
+                    .is_some_and(|id| id.to_string() == METADATA_ID);
+            }
+            Event::Tag("metadata", _, _) if inside => break,
+            Event::Text(chunk) if inside => text.push_str(chunk),
+            Event::Error(err) => return Err(format!("Parse failed: {}", err)),
+            _ => {}
+        }
+    }
+    if text.trim().is_empty() {
+        return Err("This SVG has no embedded reticle config".to_string());
+    }
+
+    let envelope: Value = serde_json::from_str(&unescape_text(text.trim()))
+        .map_err(|e| format!("Embedded config is damaged: {}", e))?;
+    let version = envelope
+        .get("format_version")
+        .and_then(Value::as_u64)
+        .ok_or("Embedded config has no format version")?;
+    if version > FORMAT_VERSION {
+        return Err(format!(
+            "Embedded config format {} is newer than this build supports ({})",
+            version, FORMAT_VERSION
+        ));
+    }
+    let config = envelope
+        .get("config")
+        .cloned()
+        .ok_or("Embedded config is missing")?;
+    config_from_value(config)
+}
+
+pub(crate) fn read_svg_config(path: &Path) -> Result<CrosshairConfig, String> {
+    let source = fs::read_to_string(path).map_err(|e| format!("Read failed: {}", e))?;
+    config_from_svg(&source).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_svg;
+
+    // `CrosshairConfig` has no Debug, so `unwrap_err` is out.
+    fn rejection(source: &str) -> String {
+        match config_from_svg(source) {
+            Ok(_) => panic!("config should be rejected"),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn exported_svg_round_trips_its_config() {
+        let mut config = CrosshairConfig {
+            size: 96,
+            arm_color: (12, 200, 77, 0.75),
+            center_gap_radius: 3.25,
+            ..CrosshairConfig::default()
+        };
+        config.spokes.truncate(3);
+        config.contrast_outline.enabled = true;
+
+        let source = generate_svg(&config).to_string();
+        let Ok(restored) = config_from_svg(&source) else {
+            panic!("embedded config should read back");
+        };
+        assert_eq!(
+            serde_json::to_value(&restored).unwrap(),
+            serde_json::to_value(&config).unwrap()
+        );
+    }
+
+    #[test]
+    fn newer_format_version_is_rejected() {
+        let envelope = json!({
+            "format_version": FORMAT_VERSION + 1,
+            "config": CrosshairConfig::default(),
+        });
+        let source = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><metadata id="{}">{}</metadata></svg>"#,
+            METADATA_ID,
+            escape_text(&envelope.to_string())
+        );
+        let err = rejection(&source);
+        assert!(err.contains("newer than this build supports"), "{}", err);
+    }
+
+    #[test]
+    fn svg_without_config_is_rejected() {
+        let plain = r#"<svg xmlns="http://www.w3.org/2000/svg"><circle r="4"/></svg>"#;
+        assert_eq!(rejection(plain), "This SVG has no embedded reticle config");
+
+        // Metadata written by other tools is not mistaken for ours.
+        let foreign = r#"<svg xmlns="http://www.w3.org/2000/svg"><metadata id="other">{"config":{}}</metadata></svg>"#;
+        assert_eq!(
+            rejection(foreign),
+            "This SVG has no embedded reticle config"
+        );
+    }
+}
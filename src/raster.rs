@@ -0,0 +1,313 @@
+// Software rasterization of generated reticles.
+// Everything runs on the CPU through resvg so exports work headless and match
+// the SVG output exactly. Animated frames are encoded to APNG or GIF here too,
+// and the preview mesh can be rendered here to diff it against the SVG.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use resvg::tiny_skia::{
+    self, Color, FillRule, GradientStop, Paint, PathBuilder, Pixmap, Shader, SpreadMode, Stroke,
+    Transform,
+};
+use resvg::usvg;
+use svg::Document;
+
+use crate::geometry::{self, FilledOutline, GradientSpan, Point};
+use crate::{ContrastOutline, CrosshairConfig, PREVIEW_CURVE_STEPS, tessellate};
+
+pub(crate) fn rasterize_document(document: &Document, size: u32) -> Result<Pixmap, String> {
+    render(document, size, size, &usvg::Options::default())
+}
+
+// Options for documents with text (contact sheet labels). Loading system fonts is
+// slow, so reticle renders skip it and text renders share one set of options.
+pub(crate) fn text_options() -> usvg::Options<'static> {
+    let mut options = usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+    options
+}
+
+pub(crate) fn rasterize_with_options(
+    document: &Document,
+    width: u32,
+    height: u32,
+    options: &usvg::Options,
+) -> Result<Pixmap, String> {
+    render(document, width, height, options)
+}
+
+fn render(
+    document: &Document,
+    width: u32,
+    height: u32,
+    options: &usvg::Options,
+) -> Result<Pixmap, String> {
+    let tree = usvg::Tree::from_str(&document.to_string(), options)
+        .map_err(|e| format!("SVG parse failed: {}", e))?;
+
+    let mut pixmap = Pixmap::new(width, height)
+        .ok_or_else(|| format!("Invalid raster size {}x{}", width, height))?;
+
+    let tree_size = tree.size();
+    let transform = Transform::from_scale(
+        width as f32 / tree_size.width(),
+        height as f32 / tree_size.height(),
+    );
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    Ok(pixmap)
+}
+
+pub(crate) fn save_png(pixmap: &Pixmap, path: &Path) -> Result<(), String> {
+    pixmap
+        .save_png(path)
+        .map_err(|e| format!("PNG write failed: {}", e))
+}
+
+// Straight-alpha RGBA bytes; tiny-skia stores premultiplied pixels.
+pub(crate) fn straight_rgba(pixmap: &Pixmap) -> Vec<u8> {
+    pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let c = pixel.demultiply();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+        .collect()
+}
+
+fn skia_color((r, g, b, a): (u8, u8, u8, f32)) -> Color {
+    Color::from_rgba8(r, g, b, (a * 255.0).round().clamp(0.0, 255.0) as u8)
+}
+
+fn skia_point((x, y): Point) -> tiny_skia::Point {
+    tiny_skia::Point::from_xy(x as f32, y as f32)
+}
+
+// Same paint as `FilledOutline::color_at`: gradients pad beyond their ends.
+fn shape_shader(shape: &FilledOutline) -> Shader<'static> {
+    let solid = Shader::SolidColor(skia_color(shape.color));
+    let stops = |start: f32, end_color| {
+        vec![
+            GradientStop::new(start, skia_color(shape.color)),
+            GradientStop::new(1.0, skia_color(end_color)),
+        ]
+    };
+    let shader = match shape.gradient {
+        None => None,
+        Some(GradientSpan::Linear {
+            from,
+            to,
+            end_color,
+        }) => tiny_skia::LinearGradient::new(
+            skia_point(from),
+            skia_point(to),
+            stops(0.0, end_color),
+            SpreadMode::Pad,
+            Transform::identity(),
+        ),
+        Some(GradientSpan::Radial {
+            center,
+            inner_r,
+            outer_r,
+            end_color,
+        }) => tiny_skia::RadialGradient::new(
+            skia_point(center),
+            skia_point(center),
+            outer_r as f32,
+            stops((inner_r / outer_r.max(f64::EPSILON)) as f32, end_color),
+            SpreadMode::Pad,
+            Transform::identity(),
+        ),
+    };
+    shader.unwrap_or(solid)
+}
+
+fn loops_path(loops: &[Vec<Point>]) -> Option<tiny_skia::Path> {
+    let mut builder = PathBuilder::new();
+    for points in loops {
+        let Some((first, rest)) = points.split_first() else {
+            continue;
+        };
+        builder.move_to(first.0 as f32, first.1 as f32);
+        for p in rest {
+            builder.line_to(p.0 as f32, p.1 as f32);
+        }
+        builder.close();
+    }
+    builder.finish()
+}
+
+// One preview shape: the optional contrast stroke, then its ear-clipped triangles.
+fn paint_preview_shape(
+    pixmap: &mut Pixmap,
+    shape: &FilledOutline,
+    contrast: Option<&ContrastOutline>,
+    transform: Transform,
+) {
+    let loops = shape.outline.flatten(PREVIEW_CURVE_STEPS);
+
+    if let Some(contrast) = contrast
+        && let Some(path) = loops_path(&loops)
+    {
+        let mut paint = Paint::default();
+        paint.set_color(skia_color(contrast.color));
+        let stroke = Stroke {
+            width: (contrast.width * 2.0) as f32,
+            ..Stroke::default()
+        };
+        pixmap.stroke_path(&path, &paint, &stroke, transform, None);
+    }
+
+    // All triangles share one path so their common edges don't leave anti-aliased seams.
+    let triangulation = tessellate::tessellate(&loops);
+    let triangles: Vec<Vec<Point>> = triangulation
+        .indices
+        .chunks_exact(3)
+        .map(|tri| {
+            tri.iter()
+                .map(|&i| triangulation.vertices[i as usize])
+                .collect()
+        })
+        .collect();
+    if let Some(path) = loops_path(&triangles) {
+        let paint = Paint {
+            shader: shape_shader(shape),
+            ..Paint::default()
+        };
+        pixmap.fill_path(&path, &paint, FillRule::Winding, transform, None);
+    }
+}
+
+// The live vector preview drawn on the CPU: the same flattened outlines and
+// triangles egui gets, so tessellation faults show up when diffed against resvg.
+pub(crate) fn rasterize_preview_mesh(config: &CrosshairConfig) -> Result<Pixmap, String> {
+    let size = config.size;
+    let mut pixmap =
+        Pixmap::new(size, size).ok_or_else(|| format!("Invalid raster size {}x{}", size, size))?;
+    let scale = size as f32 / config.design_extent() as f32;
+    let transform = Transform::from_scale(scale, scale);
+
+    let geometry = geometry::build_geometry(config);
+    let contrast = config
+        .contrast_outline
+        .is_visible()
+        .then_some(&config.contrast_outline);
+    for shape in geometry.spokes.iter().chain(&geometry.rings) {
+        paint_preview_shape(&mut pixmap, shape, contrast, transform);
+    }
+    if let Some(marker) = &geometry.marker {
+        paint_preview_shape(&mut pixmap, marker, None, transform);
+    }
+
+    Ok(pixmap)
+}
+
+// Opaque `highlight` wherever any premultiplied channel of `a` and `b` differs by
+// more than `threshold`; transparent elsewhere.
+pub(crate) fn diff_mask(
+    a: &Pixmap,
+    b: &Pixmap,
+    threshold: u8,
+    highlight: (u8, u8, u8),
+) -> Result<Pixmap, String> {
+    if (a.width(), a.height()) != (b.width(), b.height()) {
+        return Err(format!(
+            "Cannot diff {}x{} against {}x{}",
+            a.width(),
+            a.height(),
+            b.width(),
+            b.height()
+        ));
+    }
+    let mut mask = Pixmap::new(a.width(), a.height())
+        .ok_or_else(|| format!("Invalid raster size {}x{}", a.width(), a.height()))?;
+    let marked =
+        tiny_skia::PremultipliedColorU8::from_rgba(highlight.0, highlight.1, highlight.2, 255)
+            .ok_or("Invalid highlight color")?;
+    for ((out, pa), pb) in mask.pixels_mut().iter_mut().zip(a.pixels()).zip(b.pixels()) {
+        let channels =
+            |p: &tiny_skia::PremultipliedColorU8| [p.red(), p.green(), p.blue(), p.alpha()];
+        let differs = channels(pa)
+            .into_iter()
+            .zip(channels(pb))
+            .any(|(x, y)| x.abs_diff(y) > threshold);
+        if differs {
+            *out = marked;
+        }
+    }
+    Ok(mask)
+}
+
+fn frame_size(frames: &[Pixmap]) -> Result<(u32, u32), String> {
+    let first = frames.first().ok_or("Animation has no frames")?;
+    Ok((first.width(), first.height()))
+}
+
+// `loops` counts plays; 0 repeats forever (the APNG convention).
+pub(crate) fn save_apng(
+    frames: &[Pixmap],
+    fps: u32,
+    loops: u32,
+    path: &Path,
+) -> Result<(), String> {
+    let (width, height) = frame_size(frames)?;
+    let file = File::create(path).map_err(|e| format!("APNG write failed: {}", e))?;
+
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let apng_error = |e: png::EncodingError| format!("APNG write failed: {}", e);
+    encoder
+        .set_animated(frames.len() as u32, loops)
+        .map_err(apng_error)?;
+    encoder
+        .set_frame_delay(1, fps.clamp(1, u16::MAX as u32) as u16)
+        .map_err(apng_error)?;
+
+    let mut writer = encoder.write_header().map_err(apng_error)?;
+    for frame in frames {
+        writer
+            .write_image_data(&straight_rgba(frame))
+            .map_err(apng_error)?;
+    }
+    writer.finish().map_err(apng_error)
+}
+
+// GIF has 1-bit transparency and a 256-color palette per frame, so soft edges and
+// glows are quantized.
+pub(crate) fn save_gif(frames: &[Pixmap], fps: u32, loops: u32, path: &Path) -> Result<(), String> {
+    let (width, height) = frame_size(frames)?;
+    let (width, height) = (
+        u16::try_from(width).map_err(|_| format!("GIF frames cannot exceed {} px", u16::MAX))?,
+        u16::try_from(height).map_err(|_| format!("GIF frames cannot exceed {} px", u16::MAX))?,
+    );
+    let file = File::create(path).map_err(|e| format!("GIF write failed: {}", e))?;
+    let gif_error = |e: gif::EncodingError| format!("GIF write failed: {}", e);
+
+    let mut encoder =
+        gif::Encoder::new(BufWriter::new(file), width, height, &[]).map_err(gif_error)?;
+    // The loop extension counts repeats after the first play; without it viewers play once.
+    match loops {
+        0 => encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(gif_error)?,
+        1 => {}
+        n => encoder
+            .set_repeat(gif::Repeat::Finite((n - 1).min(u16::MAX as u32) as u16))
+            .map_err(gif_error)?,
+    }
+
+    // GIF delays are in hundredths of a second.
+    let delay = (100.0 / fps.max(1) as f64).round().max(1.0) as u16;
+    for pixmap in frames {
+        let mut rgba = straight_rgba(pixmap);
+        let mut frame = gif::Frame::from_rgba_speed(width, height, &mut rgba, 10);
+        frame.delay = delay;
+        frame.dispose = gif::DisposalMethod::Background;
+        encoder.write_frame(&frame).map_err(gif_error)?;
+    }
+    Ok(())
+}
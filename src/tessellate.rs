@@ -0,0 +1,382 @@
+// Polygon tessellation for preview shapes.
+// Flattened outlines can be concave (razor pinches) and can carry holes (rings),
+// so they are triangulated by ear clipping instead of being fanned as convex polygons.
+// Loops follow the even-odd rule: a loop nested inside an odd number of others is a hole.
+
+use crate::geometry::Point;
+
+const EPSILON: f64 = 1e-9;
+
+#[derive(Default)]
+pub(crate) struct Triangulation {
+    pub(crate) vertices: Vec<Point>,
+    pub(crate) indices: Vec<u32>,
+}
+
+pub(crate) fn tessellate(loops: &[Vec<Point>]) -> Triangulation {
+    let loops: Vec<Vec<Point>> = loops
+        .iter()
+        .map(|points| clean_loop(points))
+        .filter(|points| points.len() >= 3 && signed_area(points).abs() > EPSILON)
+        .collect();
+
+    let areas: Vec<f64> = loops
+        .iter()
+        .map(|points| signed_area(points).abs())
+        .collect();
+
+    // Depth = number of larger loops that contain this one; the parent is the smallest of them.
+    let mut depth = vec![0usize; loops.len()];
+    let mut parent = vec![None; loops.len()];
+    for i in 0..loops.len() {
+        for j in 0..loops.len() {
+            if i == j || areas[j] <= areas[i] || !point_in_polygon(loops[i][0], &loops[j]) {
+                continue;
+            }
+            depth[i] += 1;
+            if parent[i].is_none_or(|p: usize| areas[j] < areas[p]) {
+                parent[i] = Some(j);
+            }
+        }
+    }
+
+    let mut out = Triangulation::default();
+    for outer in 0..loops.len() {
+        if depth[outer] % 2 == 1 {
+            continue;
+        }
+
+        let mut polygon = oriented(&loops[outer], true);
+        let mut holes: Vec<Vec<Point>> = (0..loops.len())
+            .filter(|&h| depth[h] % 2 == 1 && parent[h] == Some(outer))
+            .map(|h| oriented(&loops[h], false))
+            .collect();
+        holes.sort_by(|a, b| max_x(b).total_cmp(&max_x(a)));
+
+        for hole in &holes {
+            polygon = bridge_hole(polygon, hole);
+        }
+
+        ear_clip(&polygon, &mut out);
+    }
+
+    out
+}
+
+fn clean_loop(points: &[Point]) -> Vec<Point> {
+    let mut cleaned: Vec<Point> = Vec::with_capacity(points.len());
+    for &p in points {
+        if cleaned.last().is_none_or(|&last| !same_point(last, p)) {
+            cleaned.push(p);
+        }
+    }
+    while cleaned.len() > 1 && same_point(cleaned[0], cleaned[cleaned.len() - 1]) {
+        cleaned.pop();
+    }
+    cleaned
+}
+
+fn same_point(a: Point, b: Point) -> bool {
+    (a.0 - b.0).abs() <= EPSILON && (a.1 - b.1).abs() <= EPSILON
+}
+
+fn signed_area(points: &[Point]) -> f64 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+fn oriented(points: &[Point], positive: bool) -> Vec<Point> {
+    let mut points = points.to_vec();
+    if (signed_area(&points) > 0.0) != positive {
+        points.reverse();
+    }
+    points
+}
+
+fn max_x(points: &[Point]) -> f64 {
+    points.iter().map(|p| p.0).fold(f64::MIN, f64::max)
+}
+
+fn cross(o: Point, a: Point, b: Point) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+fn point_in_polygon(p: Point, polygon: &[Point]) -> bool {
+    let mut inside = false;
+    let n = polygon.len();
+    let mut j = n - 1;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[j]);
+        if (a.1 > p.1) != (b.1 > p.1) && p.0 < (b.0 - a.0) * (p.1 - a.1) / (b.1 - a.1) + a.0 {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+fn point_in_triangle(p: Point, a: Point, b: Point, c: Point) -> bool {
+    cross(a, b, p) >= -EPSILON && cross(b, c, p) >= -EPSILON && cross(c, a, p) >= -EPSILON
+}
+
+// Splices a hole into the outer polygon through a mutually visible bridge edge,
+// turning polygon-with-hole into one weakly simple polygon (Eberly's method).
+fn bridge_hole(polygon: Vec<Point>, hole: &[Point]) -> Vec<Point> {
+    let (m_idx, m) = hole
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by(|a, b| a.1.0.total_cmp(&b.1.0))
+        .expect("hole has vertices");
+
+    // Closest edge hit by a ray from M towards +x.
+    let n = polygon.len();
+    let mut best: Option<(f64, usize)> = None;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        if (a.1 > m.1) == (b.1 > m.1) {
+            continue;
+        }
+        let x = a.0 + (m.1 - a.1) * (b.0 - a.0) / (b.1 - a.1);
+        if x >= m.0 && best.is_none_or(|(bx, _)| x < bx) {
+            best = Some((x, i));
+        }
+    }
+
+    let Some((hit_x, edge)) = best else {
+        return polygon;
+    };
+    let hit = (hit_x, m.1);
+    let (a, b) = (edge, (edge + 1) % n);
+    let mut p_idx = if polygon[a].0 > polygon[b].0 { a } else { b };
+
+    // A reflex vertex inside triangle (M, hit, P) would block the bridge; pick the one
+    // closest in angle to the ray instead.
+    let p = polygon[p_idx];
+    let mut best_angle = f64::MAX;
+    for i in 0..n {
+        let v = polygon[i];
+        if i == p_idx || same_point(v, p) {
+            continue;
+        }
+        let prev = polygon[(i + n - 1) % n];
+        let next = polygon[(i + 1) % n];
+        let reflex = cross(prev, v, next) <= 0.0;
+        let inside = if p.1 >= m.1 {
+            point_in_triangle(v, m, hit, p)
+        } else {
+            point_in_triangle(v, m, p, hit)
+        };
+        if reflex && inside {
+            let angle = (v.1 - m.1).abs().atan2(v.0 - m.0);
+            if angle < best_angle {
+                best_angle = angle;
+                p_idx = i;
+            }
+        }
+    }
+
+    let mut merged = Vec::with_capacity(n + hole.len() + 2);
+    merged.extend_from_slice(&polygon[..=p_idx]);
+    merged.extend((0..=hole.len()).map(|k| hole[(m_idx + k) % hole.len()]));
+    merged.extend_from_slice(&polygon[p_idx..]);
+    merged
+}
+
+fn ear_clip(polygon: &[Point], out: &mut Triangulation) {
+    let base = out.vertices.len() as u32;
+    out.vertices.extend_from_slice(polygon);
+
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+    let mut misses = 0;
+    let mut i = 0;
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let (ip, ic, inx) = (
+            remaining[(i + n - 1) % n],
+            remaining[i % n],
+            remaining[(i + 1) % n],
+        );
+
+        // After a full lap without an ear the remainder is degenerate; clip anyway.
+        let is_ear = misses >= n || is_ear(polygon, &remaining, [ip, ic, inx]);
+        if is_ear {
+            out.indices
+                .extend([base + ip as u32, base + ic as u32, base + inx as u32]);
+            remaining.remove(i % n);
+            misses = 0;
+            i %= remaining.len();
+        } else {
+            misses += 1;
+            i = (i + 1) % n;
+        }
+    }
+
+    if let [a, b, c] = remaining[..] {
+        out.indices
+            .extend([base + a as u32, base + b as u32, base + c as u32]);
+    }
+}
+
+fn is_ear(polygon: &[Point], remaining: &[usize], corners: [usize; 3]) -> bool {
+    let [a, b, c] = corners.map(|k| polygon[k]);
+    if cross(a, b, c) <= EPSILON {
+        return false;
+    }
+
+    remaining.iter().all(|&k| {
+        if corners.contains(&k) {
+            return true;
+        }
+        let p = polygon[k];
+        same_point(p, a) || same_point(p, b) || same_point(p, c) || !point_in_triangle(p, a, b, c)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::ring_outline;
+
+    fn circle(center: Point, r: f64, segments: usize) -> Vec<Point> {
+        (0..segments)
+            .map(|i| {
+                let t = i as f64 / segments as f64 * std::f64::consts::TAU;
+                (center.0 + r * t.cos(), center.1 + r * t.sin())
+            })
+            .collect()
+    }
+
+    // Sum of absolute triangle areas; overlapping or flipped triangles inflate it.
+    fn triangle_area(triangulation: &Triangulation) -> f64 {
+        triangulation
+            .indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [t[0], t[1], t[2]].map(|i| triangulation.vertices[i as usize]);
+                cross(a, b, c).abs() / 2.0
+            })
+            .sum()
+    }
+
+    fn assert_indices_valid(triangulation: &Triangulation) {
+        assert_eq!(triangulation.indices.len() % 3, 0);
+        let count = triangulation.vertices.len() as u32;
+        assert!(triangulation.indices.iter().all(|&i| i < count));
+    }
+
+    #[test]
+    fn annulus_area_matches_ring() {
+        let (outer, inner) = (40.0, 25.0);
+        let loops = vec![
+            circle((50.0, 50.0), outer, 720),
+            circle((50.0, 50.0), inner, 720),
+        ];
+        let triangulation = tessellate(&loops);
+        assert_indices_valid(&triangulation);
+
+        let polygon_area = signed_area(&loops[0]).abs() - signed_area(&loops[1]).abs();
+        let area = triangle_area(&triangulation);
+        assert!((area - polygon_area).abs() < 1e-6 * polygon_area);
+
+        let exact = std::f64::consts::PI * (outer * outer - inner * inner);
+        assert!((area - exact).abs() < 1e-3 * exact, "{} vs {}", area, exact);
+    }
+
+    #[test]
+    fn ring_outline_fills_annulus() {
+        let (outer, inner) = (30.0, 20.0);
+        let loops = ring_outline((32.0, 32.0), outer, inner).flatten(32);
+        let triangulation = tessellate(&loops);
+        assert_indices_valid(&triangulation);
+
+        let exact = std::f64::consts::PI * (outer * outer - inner * inner);
+        let area = triangle_area(&triangulation);
+        assert!((area - exact).abs() < 1e-2 * exact, "{} vs {}", area, exact);
+    }
+
+    #[test]
+    fn rectangle_with_hole() {
+        let outer = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 6.0), (0.0, 6.0)];
+        // Same winding as the outer loop; nesting alone makes it a hole.
+        let hole = vec![(2.0, 2.0), (5.0, 2.0), (5.0, 4.0), (2.0, 4.0)];
+        let triangulation = tessellate(&[outer, hole]);
+        assert_indices_valid(&triangulation);
+        assert!((triangle_area(&triangulation) - (60.0 - 6.0)).abs() < 1e-9);
+
+        // No triangle may cover the hole's center.
+        let center = (3.5, 3.0);
+        assert!(triangulation.indices.chunks_exact(3).all(|t| {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| triangulation.vertices[i as usize]);
+            let [a, b, c] = if cross(a, b, c) < 0.0 {
+                [a, c, b]
+            } else {
+                [a, b, c]
+            };
+            !point_in_triangle(center, a, b, c)
+        }));
+    }
+
+    #[test]
+    fn nested_island_is_filled() {
+        let outer = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        let hole = vec![(2.0, 2.0), (8.0, 2.0), (8.0, 8.0), (2.0, 8.0)];
+        let island = vec![(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0)];
+        let triangulation = tessellate(&[outer, hole, island]);
+        assert!((triangle_area(&triangulation) - (100.0 - 36.0 + 4.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn concave_polygon_keeps_its_area() {
+        // An arrowhead: one reflex vertex.
+        let points = vec![(0.0, 0.0), (5.0, 2.0), (10.0, 0.0), (5.0, 8.0)];
+        let triangulation = tessellate(std::slice::from_ref(&points));
+        assert_eq!(triangulation.indices.len(), 6);
+        assert!((triangle_area(&triangulation) - signed_area(&points).abs()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn degenerate_input_does_not_panic() {
+        let cases: Vec<Vec<Vec<Point>>> = vec![
+            vec![],
+            vec![vec![]],
+            vec![vec![(1.0, 1.0)]],
+            vec![vec![(0.0, 0.0), (1.0, 1.0)]],
+            // Collinear points have no area.
+            vec![vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]],
+            // Repeated points only.
+            vec![vec![(2.0, 2.0); 5]],
+            // A closed loop that repeats its first point, plus collinear runs.
+            vec![vec![
+                (0.0, 0.0),
+                (2.0, 0.0),
+                (4.0, 0.0),
+                (4.0, 4.0),
+                (4.0, 4.0),
+                (0.0, 4.0),
+                (0.0, 0.0),
+            ]],
+            // Self-intersecting bow tie.
+            vec![vec![(0.0, 0.0), (4.0, 4.0), (4.0, 0.0), (0.0, 4.0)]],
+            // Hole touching the outer edge.
+            vec![
+                vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+                vec![(0.0, 1.0), (2.0, 1.0), (2.0, 3.0), (0.0, 3.0)],
+            ],
+        ];
+        for loops in &cases {
+            assert_indices_valid(&tessellate(loops));
+        }
+
+        let square = tessellate(&cases[6]);
+        assert!((triangle_area(&square) - 16.0).abs() < 1e-9);
+        assert!(tessellate(&cases[4]).indices.is_empty());
+    }
+}
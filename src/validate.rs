@@ -0,0 +1,399 @@
+// Sanity checks for reticle configs.
+// Errors mark geometry that renders wrong (flipped spokes, NaN paths, impossible canvases);
+// warnings mark values the renderers silently clamp or that push shapes off the canvas.
+
+use std::fmt;
+
+use crate::geometry::{anchor_radius, spoke_base_radius, spoke_tip_radius};
+use crate::{
+    CrosshairConfig, GeometryUnits, MAX_CANVAS_SIZE, MIN_CANVAS_SIZE, RingStyle, SpokeStyle,
+    animation,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Issue {
+    pub(crate) severity: Severity,
+    // Path to the offending value, e.g. `rings[1].thickness`.
+    pub(crate) field: String,
+    pub(crate) message: String,
+}
+
+impl Issue {
+    fn warning(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
+    fn error(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}: {}: {}", level, self.field, self.message)
+    }
+}
+
+pub(crate) fn has_errors(issues: &[Issue]) -> bool {
+    issues.iter().any(|issue| issue.severity == Severity::Error)
+}
+
+// One-line tally for status bars, e.g. "1 error, 2 warnings".
+pub(crate) fn summarize(issues: &[Issue]) -> String {
+    let errors = issues
+        .iter()
+        .filter(|issue| issue.severity == Severity::Error)
+        .count();
+    let warnings = issues.len() - errors;
+    let plural = |n: usize, word: &str| format!("{} {}{}", n, word, if n == 1 { "" } else { "s" });
+    match (errors, warnings) {
+        (0, 0) => "no issues".to_string(),
+        (0, w) => plural(w, "warning"),
+        (e, 0) => plural(e, "error"),
+        (e, w) => format!("{}, {}", plural(e, "error"), plural(w, "warning")),
+    }
+}
+
+// Issues come back errors first, then in field order.
+pub(crate) fn validate(config: &CrosshairConfig) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    check_canvas(config, &mut issues);
+    check_rings(config, &mut issues);
+    check_spokes(config, &mut issues);
+    check_decorations(config, &mut issues);
+    check_animation(config, &mut issues);
+
+    issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+    issues
+}
+
+fn check_length(issues: &mut Vec<Issue>, field: String, value: f64) -> bool {
+    if !value.is_finite() {
+        issues.push(Issue::error(field, "is not a finite number"));
+        false
+    } else if value < 0.0 {
+        issues.push(Issue::error(field, format!("is negative ({})", value)));
+        false
+    } else {
+        true
+    }
+}
+
+fn check_alpha(issues: &mut Vec<Issue>, field: String, alpha: f32) {
+    if !(0.0..=1.0).contains(&alpha) {
+        issues.push(Issue::warning(
+            field,
+            format!("opacity {} is outside 0..1 and will be clamped", alpha),
+        ));
+    }
+}
+
+fn check_canvas(config: &CrosshairConfig, issues: &mut Vec<Issue>) {
+    if !(MIN_CANVAS_SIZE..=MAX_CANVAS_SIZE).contains(&config.size) {
+        issues.push(Issue::error(
+            "size",
+            format!(
+                "canvas size {} is outside {}..={}",
+                config.size, MIN_CANVAS_SIZE, MAX_CANVAS_SIZE
+            ),
+        ));
+    }
+    if config.units == GeometryUnits::Design
+        && (!config.design_size.is_finite() || config.design_size <= 0.0)
+    {
+        issues.push(Issue::error("design_size", "must be a positive number"));
+    }
+}
+
+fn check_rings(config: &CrosshairConfig, issues: &mut Vec<Issue>) {
+    let canvas_radius = config.design_extent() / 2.0;
+
+    for (idx, ring) in config.rings.iter().enumerate() {
+        let field = |name: &str| format!("rings[{}].{}", idx, name);
+        let radius_ok = check_length(issues, field("outer_radius"), ring.outer_radius);
+        let thickness_ok = check_length(issues, field("thickness"), ring.thickness);
+        check_alpha(issues, field("color"), ring.color.3);
+        check_alpha(issues, field("opacity"), ring.opacity);
+        if !(radius_ok && thickness_ok) {
+            continue;
+        }
+
+        if ring.thickness > ring.outer_radius {
+            issues.push(Issue::warning(
+                field("thickness"),
+                format!(
+                    "thickness {} exceeds the outer radius {}; the ring is drawn as a solid disc",
+                    ring.thickness, ring.outer_radius
+                ),
+            ));
+        } else if ring.thickness == 0.0 {
+            issues.push(Issue::warning(
+                field("thickness"),
+                "zero-thickness ring is invisible",
+            ));
+        }
+        if ring.outer_radius > canvas_radius {
+            issues.push(Issue::warning(
+                field("outer_radius"),
+                format!(
+                    "outer radius {} overflows the canvas radius {}",
+                    ring.outer_radius, canvas_radius
+                ),
+            ));
+        }
+
+        match ring.style {
+            RingStyle::Full => {}
+            RingStyle::Segmented {
+                start_angle,
+                end_angle,
+                count,
+                gap,
+            } => {
+                let span = (end_angle - start_angle).rem_euclid(360.0);
+                let span = if span == 0.0 { 360.0 } else { span };
+                if count == 0 {
+                    issues.push(Issue::warning(field("style.count"), "no segments to draw"));
+                } else if gap * count as f64 >= span {
+                    issues.push(Issue::warning(
+                        field("style.gap"),
+                        "gaps cover the whole span; no segments are drawn",
+                    ));
+                }
+            }
+            RingStyle::SpokeGaps { gap } => {
+                if gap * config.spokes.len() as f64 >= 360.0 {
+                    issues.push(Issue::warning(
+                        field("style.gap"),
+                        "spoke gaps cover the whole ring",
+                    ));
+                }
+            }
+        }
+    }
+
+    if !config.rings.is_empty() && config.spoke_anchor_ring >= config.rings.len() {
+        issues.push(Issue::warning(
+            "spoke_anchor_ring",
+            format!(
+                "ring {} does not exist; spokes anchor to ring 1",
+                config.spoke_anchor_ring + 1
+            ),
+        ));
+    }
+}
+
+fn check_spokes(config: &CrosshairConfig, issues: &mut Vec<Issue>) {
+    for (name, value) in [
+        ("gap_from_ring", config.gap_from_ring),
+        ("center_gap_radius", config.center_gap_radius),
+        ("spoke_base_width", config.spoke_base_width),
+        ("spoke_tip_width", config.spoke_tip_width),
+    ] {
+        check_length(issues, name.to_string(), value);
+    }
+    check_alpha(issues, "arm_color".to_string(), config.arm_color.3);
+
+    let anchor = anchor_radius(config);
+    for (idx, spoke) in config.spokes.iter().enumerate() {
+        let field = |name: &str| format!("spokes[{}].{}", idx, name);
+        let overrides = [
+            ("base_width", spoke.base_width),
+            ("tip_width", spoke.tip_width),
+            ("gap_from_ring", spoke.gap_from_ring),
+            ("tip_radius", spoke.tip_radius),
+        ];
+        let mut lengths_ok = true;
+        for (name, value) in overrides {
+            if let Some(value) = value {
+                lengths_ok &= check_length(issues, field(name), value);
+            }
+        }
+
+        if !spoke.angle.is_finite() {
+            issues.push(Issue::error(field("angle"), "is not a finite number"));
+        } else if !(0.0..=360.0).contains(&spoke.angle) {
+            issues.push(Issue::warning(
+                field("angle"),
+                format!("angle {} is outside 0..360", spoke.angle),
+            ));
+        }
+        if let Some(color) = spoke.color {
+            check_alpha(issues, field("color"), color.3);
+        }
+        if !lengths_ok {
+            continue;
+        }
+
+        let tip_r = spoke_tip_radius(config, spoke);
+        let base_r = spoke_base_radius(config, spoke);
+        if spoke.gap_from_ring(config) > anchor {
+            issues.push(Issue::warning(
+                field("gap_from_ring"),
+                format!(
+                    "gap {} is larger than the anchor radius {}; the base is clamped to the center",
+                    spoke.gap_from_ring(config),
+                    anchor
+                ),
+            ));
+        }
+        if base_r < tip_r {
+            issues.push(Issue::error(
+                field("tip_radius"),
+                format!(
+                    "base radius {:.2} is inside the tip radius {:.2}; the spoke would be drawn flipped",
+                    base_r, tip_r
+                ),
+            ));
+        } else if base_r == tip_r {
+            issues.push(Issue::warning(
+                field("tip_radius"),
+                "base and tip radius coincide; the spoke has no length",
+            ));
+        }
+
+        match spoke.style(config) {
+            SpokeStyle::Chevron { thickness } if thickness <= 0.0 => {
+                issues.push(Issue::warning(
+                    field("style"),
+                    "chevron arms have no thickness",
+                ));
+            }
+            SpokeStyle::Razor { pinch } if !(0.0..=1.0).contains(&pinch) => {
+                issues.push(Issue::warning(
+                    field("style"),
+                    format!("razor pinch {} is outside 0..1", pinch),
+                ));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_decorations(config: &CrosshairConfig, issues: &mut Vec<Issue>) {
+    let marker = &config.center_marker;
+    check_length(issues, "center_marker.size".to_string(), marker.size);
+    check_length(
+        issues,
+        "center_marker.thickness".to_string(),
+        marker.thickness,
+    );
+    check_alpha(issues, "center_marker.color".to_string(), marker.color.3);
+    check_alpha(issues, "center_marker.opacity".to_string(), marker.opacity);
+
+    let outline = &config.contrast_outline;
+    check_length(issues, "contrast_outline.width".to_string(), outline.width);
+    check_alpha(
+        issues,
+        "contrast_outline.color".to_string(),
+        outline.color.3,
+    );
+
+    for (name, value) in [
+        ("blur_radius", config.blur_radius),
+        ("glow_radius", config.glow_radius),
+    ] {
+        check_length(issues, name.to_string(), value as f64);
+    }
+}
+
+fn check_animation(config: &CrosshairConfig, issues: &mut Vec<Issue>) {
+    for (idx, track) in config.animation.tracks.iter().enumerate() {
+        let field = format!("animation.tracks[{}]", idx);
+        if track.field.is_empty() {
+            if !track.keyframes.is_empty() {
+                issues.push(Issue::warning(field, "track has no field and is ignored"));
+            }
+        } else if let Some(message) = animation::track_error(config, track) {
+            issues.push(Issue::error(field, message));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(issues: &'a [Issue], field: &str) -> &'a Issue {
+        issues
+            .iter()
+            .find(|issue| issue.field == field)
+            .unwrap_or_else(|| panic!("no issue for {} in {:?}", field, issues))
+    }
+
+    #[test]
+    fn default_config_has_no_errors() {
+        assert!(!has_errors(&validate(&CrosshairConfig::default())));
+    }
+
+    #[test]
+    fn flipped_spoke_is_an_error() {
+        let mut config = CrosshairConfig::default();
+        // The base sits at the anchor ring's inner edge minus the gap, well inside 200.
+        config.spokes[0].tip_radius = Some(200.0);
+        let issues = validate(&config);
+        let issue = find(&issues, "spokes[0].tip_radius");
+        assert_eq!(issue.severity, Severity::Error);
+        assert!(issue.message.contains("flipped"), "{}", issue.message);
+    }
+
+    #[test]
+    fn ring_overflowing_the_canvas_is_a_warning() {
+        let mut config = CrosshairConfig::default();
+        config.rings[0].outer_radius = config.design_extent() / 2.0 + 12.0;
+        let issues = validate(&config);
+        assert_eq!(
+            find(&issues, "rings[0].outer_radius").severity,
+            Severity::Warning
+        );
+        assert!(!has_errors(&issues));
+    }
+
+    #[test]
+    fn errors_sort_before_warnings() {
+        let mut config = CrosshairConfig::default();
+        config.rings[0].outer_radius = config.design_extent() / 2.0 + 12.0;
+        config.rings[0].opacity = 2.0;
+        config.spokes[3].tip_radius = Some(200.0);
+        let issues = validate(&config);
+        assert!(issues.len() >= 3);
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert!(
+            issues
+                .windows(2)
+                .all(|pair| pair[0].severity >= pair[1].severity)
+        );
+    }
+
+    #[test]
+    fn summary_pluralizes_counts() {
+        let error = || Issue::error("size", "bad");
+        let warning = || Issue::warning("size", "odd");
+        assert_eq!(summarize(&[]), "no issues");
+        assert_eq!(summarize(&[warning()]), "1 warning");
+        assert_eq!(summarize(&[error(), error()]), "2 errors");
+        assert_eq!(
+            summarize(&[error(), warning(), warning()]),
+            "1 error, 2 warnings"
+        );
+    }
+}
@@ -0,0 +1,220 @@
+// Windows ICO and CUR encoding.
+// Both formats share one container: an ICONDIR header, one directory entry per
+// image, then the images. Images are stored as 32-bit BGRA DIBs (straight alpha,
+// bottom-up, with an empty AND mask), which every Windows version reads; PNG
+// payloads are not accepted in cursors on older systems.
+
+use resvg::tiny_skia::Pixmap;
+
+use crate::raster::straight_rgba;
+
+const ICONDIR_SIZE: u32 = 6;
+const ENTRY_SIZE: u32 = 16;
+const BITMAPINFOHEADER_SIZE: u32 = 40;
+// Directory entries store sizes in a byte; 0 means 256.
+const MAX_ICON_SIZE: u32 = 256;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IconKind {
+    Icon,
+    // Cursors carry a hotspot, placed at the image center.
+    Cursor,
+}
+
+fn dib(pixmap: &Pixmap) -> Vec<u8> {
+    let (width, height) = (pixmap.width(), pixmap.height());
+    let rgba = straight_rgba(pixmap);
+    // 1-bit AND mask rows are padded to 32 bits; all zero since alpha does the masking.
+    let mask_stride = width.div_ceil(32) * 4;
+    let xor_size = width * height * 4;
+    let mask_size = mask_stride * height;
+
+    let mut out = Vec::with_capacity((BITMAPINFOHEADER_SIZE + xor_size + mask_size) as usize);
+    let push_u32 = |out: &mut Vec<u8>, v: u32| out.extend_from_slice(&v.to_le_bytes());
+    let push_u16 = |out: &mut Vec<u8>, v: u16| out.extend_from_slice(&v.to_le_bytes());
+
+    push_u32(&mut out, BITMAPINFOHEADER_SIZE);
+    push_u32(&mut out, width);
+    // The height covers both the color bitmap and the mask.
+    push_u32(&mut out, height * 2);
+    push_u16(&mut out, 1); // planes
+    push_u16(&mut out, 32); // bits per pixel
+    push_u32(&mut out, 0); // BI_RGB
+    push_u32(&mut out, xor_size + mask_size);
+    for _ in 0..4 {
+        // Resolution and palette fields are unused.
+        push_u32(&mut out, 0);
+    }
+
+    for row in (0..height).rev() {
+        let start = (row * width * 4) as usize;
+        for px in rgba[start..start + (width * 4) as usize].chunks_exact(4) {
+            out.extend_from_slice(&[px[2], px[1], px[0], px[3]]);
+        }
+    }
+    out.resize(out.len() + mask_size as usize, 0);
+    out
+}
+
+pub(crate) fn encode(images: &[Pixmap], kind: IconKind) -> Result<Vec<u8>, String> {
+    if images.is_empty() {
+        return Err("No images to encode".to_string());
+    }
+    if let Some(big) = images
+        .iter()
+        .find(|p| p.width() > MAX_ICON_SIZE || p.height() > MAX_ICON_SIZE)
+    {
+        return Err(format!(
+            "{} px is larger than the {} px ICO/CUR limit",
+            big.width(),
+            MAX_ICON_SIZE
+        ));
+    }
+
+    let payloads: Vec<Vec<u8>> = images.iter().map(dib).collect();
+    let mut out = Vec::new();
+    let push_u32 = |out: &mut Vec<u8>, v: u32| out.extend_from_slice(&v.to_le_bytes());
+    let push_u16 = |out: &mut Vec<u8>, v: u16| out.extend_from_slice(&v.to_le_bytes());
+
+    push_u16(&mut out, 0);
+    push_u16(&mut out, if kind == IconKind::Icon { 1 } else { 2 });
+    push_u16(&mut out, images.len() as u16);
+
+    let mut offset = ICONDIR_SIZE + ENTRY_SIZE * images.len() as u32;
+    for (pixmap, payload) in images.iter().zip(&payloads) {
+        let (width, height) = (pixmap.width(), pixmap.height());
+        out.push((width % MAX_ICON_SIZE) as u8);
+        out.push((height % MAX_ICON_SIZE) as u8);
+        out.push(0); // palette size
+        out.push(0); // reserved
+        match kind {
+            IconKind::Icon => {
+                push_u16(&mut out, 1); // planes
+                push_u16(&mut out, 32); // bits per pixel
+            }
+            IconKind::Cursor => {
+                push_u16(&mut out, (width / 2) as u16);
+                push_u16(&mut out, (height / 2) as u16);
+            }
+        }
+        push_u32(&mut out, payload.len() as u32);
+        push_u32(&mut out, offset);
+        offset += payload.len() as u32;
+    }
+
+    for payload in payloads {
+        out.extend_from_slice(&payload);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use resvg::tiny_skia::ColorU8;
+
+    fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+    }
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn filled(size: u32, color: ColorU8) -> Pixmap {
+        let mut pixmap = Pixmap::new(size, size).unwrap();
+        pixmap.pixels_mut().fill(color.premultiply());
+        pixmap
+    }
+
+    #[test]
+    fn icon_directory_and_dib_layout() {
+        let images = [
+            filled(16, ColorU8::from_rgba(10, 20, 30, 255)),
+            filled(33, ColorU8::from_rgba(0, 0, 0, 0)),
+        ];
+        let bytes = encode(&images, IconKind::Icon).unwrap();
+
+        // ICONDIR: reserved, type 1 = icon, image count.
+        assert_eq!(
+            [u16_at(&bytes, 0), u16_at(&bytes, 2), u16_at(&bytes, 4)],
+            [0, 1, 2]
+        );
+
+        let mut expected_offset = ICONDIR_SIZE + 2 * ENTRY_SIZE;
+        for (idx, size) in [16u32, 33].into_iter().enumerate() {
+            let entry = (ICONDIR_SIZE + idx as u32 * ENTRY_SIZE) as usize;
+            assert_eq!(bytes[entry] as u32, size);
+            assert_eq!(bytes[entry + 1] as u32, size);
+            assert_eq!(u16_at(&bytes, entry + 4), 1); // planes
+            assert_eq!(u16_at(&bytes, entry + 6), 32); // bits per pixel
+
+            // AND mask rows pad to 4 bytes: 4 for 16 px, 8 for 33 px.
+            let mask_stride = size.div_ceil(32) * 4;
+            let image_size = BITMAPINFOHEADER_SIZE + size * size * 4 + mask_stride * size;
+            assert_eq!(u32_at(&bytes, entry + 8), image_size);
+            assert_eq!(u32_at(&bytes, entry + 12), expected_offset);
+
+            let dib = expected_offset as usize;
+            assert_eq!(u32_at(&bytes, dib), BITMAPINFOHEADER_SIZE);
+            assert_eq!(u32_at(&bytes, dib + 4), size);
+            assert_eq!(u32_at(&bytes, dib + 8), size * 2);
+            assert_eq!(u16_at(&bytes, dib + 14), 32);
+            assert_eq!(u32_at(&bytes, dib + 20), image_size - BITMAPINFOHEADER_SIZE);
+            expected_offset += image_size;
+        }
+        assert_eq!(bytes.len() as u32, expected_offset);
+
+        // First pixel of the 16 px image is BGRA.
+        let first_pixel = (ICONDIR_SIZE + 2 * ENTRY_SIZE + BITMAPINFOHEADER_SIZE) as usize;
+        assert_eq!(&bytes[first_pixel..first_pixel + 4], &[30, 20, 10, 255]);
+    }
+
+    #[test]
+    fn cursor_entries_carry_center_hotspot() {
+        let bytes = encode(
+            &[filled(32, ColorU8::from_rgba(255, 255, 255, 255))],
+            IconKind::Cursor,
+        )
+        .unwrap();
+        assert_eq!(u16_at(&bytes, 2), 2); // type 2 = cursor
+        let entry = ICONDIR_SIZE as usize;
+        assert_eq!(
+            (u16_at(&bytes, entry + 4), u16_at(&bytes, entry + 6)),
+            (16, 16)
+        );
+    }
+
+    #[test]
+    fn rows_are_stored_bottom_up() {
+        let mut pixmap = Pixmap::new(1, 2).unwrap();
+        pixmap.pixels_mut()[0] = ColorU8::from_rgba(255, 0, 0, 255).premultiply();
+        pixmap.pixels_mut()[1] = ColorU8::from_rgba(0, 0, 255, 255).premultiply();
+        let bytes = encode(&[pixmap], IconKind::Icon).unwrap();
+        let pixels = (ICONDIR_SIZE + ENTRY_SIZE + BITMAPINFOHEADER_SIZE) as usize;
+        // The bottom (blue) row comes first.
+        assert_eq!(
+            &bytes[pixels..pixels + 8],
+            &[255, 0, 0, 255, 0, 0, 255, 255]
+        );
+    }
+
+    #[test]
+    fn size_limits() {
+        let bytes = encode(
+            &[filled(256, ColorU8::from_rgba(0, 0, 0, 0))],
+            IconKind::Icon,
+        )
+        .unwrap();
+        // 256 is written as 0.
+        assert_eq!(bytes[ICONDIR_SIZE as usize], 0);
+        assert!(
+            encode(
+                &[filled(257, ColorU8::from_rgba(0, 0, 0, 0))],
+                IconKind::Icon
+            )
+            .is_err()
+        );
+        assert!(encode(&[], IconKind::Cursor).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+// X11 Xcursor file encoding.
+// Layout follows libXcursor: a file header, a table of contents, then one image
+// chunk per nominal size. Every field is a little-endian CARD32 and pixels are
+// premultiplied ARGB, which is what tiny-skia already stores (as RGBA).
+
+use resvg::tiny_skia::Pixmap;
+
+const MAGIC: &[u8; 4] = b"Xcur";
+const FILE_HEADER_SIZE: u32 = 16;
+const FILE_VERSION: u32 = 0x0001_0000;
+const TOC_ENTRY_SIZE: u32 = 12;
+const IMAGE_TYPE: u32 = 0xfffd_0002;
+const IMAGE_HEADER_SIZE: u32 = 36;
+const IMAGE_VERSION: u32 = 1;
+
+pub(crate) struct CursorImage<'a> {
+    // Size the cursor theme asks for (XCURSOR_SIZE); usually the pixel size.
+    pub(crate) nominal_size: u32,
+    pub(crate) pixmap: &'a Pixmap,
+    pub(crate) hotspot: (u32, u32),
+}
+
+pub(crate) fn encode(images: &[CursorImage]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let push = |out: &mut Vec<u8>, value: u32| out.extend_from_slice(&value.to_le_bytes());
+
+    out.extend_from_slice(MAGIC);
+    push(&mut out, FILE_HEADER_SIZE);
+    push(&mut out, FILE_VERSION);
+    push(&mut out, images.len() as u32);
+
+    let mut position = FILE_HEADER_SIZE + TOC_ENTRY_SIZE * images.len() as u32;
+    for image in images {
+        push(&mut out, IMAGE_TYPE);
+        push(&mut out, image.nominal_size);
+        push(&mut out, position);
+        position += IMAGE_HEADER_SIZE + 4 * image.pixmap.width() * image.pixmap.height();
+    }
+
+    for image in images {
+        let (width, height) = (image.pixmap.width(), image.pixmap.height());
+        for value in [
+            IMAGE_HEADER_SIZE,
+            IMAGE_TYPE,
+            image.nominal_size,
+            IMAGE_VERSION,
+            width,
+            height,
+            image.hotspot.0.min(width.saturating_sub(1)),
+            image.hotspot.1.min(height.saturating_sub(1)),
+            // Delay between animation frames in ms; unused for static cursors.
+            0,
+        ] {
+            push(&mut out, value);
+        }
+        for pixel in image.pixmap.pixels() {
+            let argb = (pixel.alpha() as u32) << 24
+                | (pixel.red() as u32) << 16
+                | (pixel.green() as u32) << 8
+                | pixel.blue() as u32;
+            push(&mut out, argb);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use resvg::tiny_skia::ColorU8;
+
+    fn card32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn sample_pixmap() -> Pixmap {
+        let mut pixmap = Pixmap::new(2, 2).unwrap();
+        let colors = [
+            ColorU8::from_rgba(255, 0, 0, 255),
+            ColorU8::from_rgba(0, 255, 0, 255),
+            ColorU8::from_rgba(0, 0, 255, 255),
+            ColorU8::from_rgba(255, 255, 255, 128),
+        ];
+        for (pixel, color) in pixmap.pixels_mut().iter_mut().zip(colors) {
+            *pixel = color.premultiply();
+        }
+        pixmap
+    }
+
+    #[test]
+    fn single_image_layout() {
+        let pixmap = sample_pixmap();
+        let bytes = encode(&[CursorImage {
+            nominal_size: 24,
+            pixmap: &pixmap,
+            hotspot: (1, 1),
+        }]);
+
+        let toc_end = (FILE_HEADER_SIZE + TOC_ENTRY_SIZE) as usize;
+        let chunk_size = (IMAGE_HEADER_SIZE + 4 * 2 * 2) as usize;
+        assert_eq!(bytes.len(), toc_end + chunk_size);
+
+        // File header.
+        assert_eq!(&bytes[0..4], MAGIC);
+        assert_eq!(card32(&bytes, 4), FILE_HEADER_SIZE);
+        assert_eq!(card32(&bytes, 8), FILE_VERSION);
+        assert_eq!(card32(&bytes, 12), 1);
+
+        // Table of contents: type, subtype (nominal size), chunk position.
+        assert_eq!(card32(&bytes, 16), IMAGE_TYPE);
+        assert_eq!(card32(&bytes, 20), 24);
+        assert_eq!(card32(&bytes, 24) as usize, toc_end);
+
+        // Image chunk header.
+        let chunk = &bytes[toc_end..];
+        let header: Vec<u32> = (0..9).map(|i| card32(chunk, i * 4)).collect();
+        assert_eq!(
+            header,
+            [
+                IMAGE_HEADER_SIZE,
+                IMAGE_TYPE,
+                24,
+                IMAGE_VERSION,
+                2,
+                2,
+                1,
+                1,
+                0
+            ]
+        );
+
+        // Premultiplied ARGB, row-major from the top-left.
+        let pixels: Vec<u32> = (0..4)
+            .map(|i| card32(chunk, IMAGE_HEADER_SIZE as usize + i * 4))
+            .collect();
+        assert_eq!(pixels, [0xffff_0000, 0xff00_ff00, 0xff00_00ff, 0x8080_8080]);
+    }
+
+    #[test]
+    fn toc_positions_follow_chunk_sizes() {
+        let small = sample_pixmap();
+        let large = Pixmap::new(3, 3).unwrap();
+        let bytes = encode(&[
+            CursorImage {
+                nominal_size: 2,
+                pixmap: &small,
+                hotspot: (5, 5),
+            },
+            CursorImage {
+                nominal_size: 3,
+                pixmap: &large,
+                hotspot: (1, 1),
+            },
+        ]);
+
+        let first = (FILE_HEADER_SIZE + 2 * TOC_ENTRY_SIZE) as usize;
+        let second = first + (IMAGE_HEADER_SIZE + 4 * 2 * 2) as usize;
+        assert_eq!(card32(&bytes, 12), 2);
+        assert_eq!(card32(&bytes, 24) as usize, first);
+        assert_eq!(card32(&bytes, 36) as usize, second);
+        assert_eq!(
+            bytes.len(),
+            second + (IMAGE_HEADER_SIZE + 4 * 3 * 3) as usize
+        );
+
+        // Out-of-range hotspots are clamped into the image.
+        assert_eq!(
+            (card32(&bytes, first + 24), card32(&bytes, first + 28)),
+            (1, 1)
+        );
+        assert_eq!(card32(&bytes, second + 8), 3);
+    }
+}